@@ -374,6 +374,49 @@ namespace {
             % creator_id % account_id)
         .str();
   }
+
+  /**
+   * Generates a query which checks that the total size of keys and values of
+   * the account details after the given detail is set does not exceed the
+   * MaxAccountDetailsSize setting, if it is set. Values which are not
+   * decimal numbers representable as bigint are ignored
+   */
+  std::string checkAccountDetailsSize(const std::string &account_id,
+                                      const std::string &writer,
+                                      const std::string &key,
+                                      const std::string &value) {
+    return (boost::format(R"(
+          SELECT COALESCE(
+              (
+                  SELECT
+                      sum(octet_length(detail.key)
+                          + octet_length(detail.value))
+                  FROM
+                      account,
+                      jsonb_each(jsonb_set(
+                          CASE
+                              WHEN data ? %2% THEN data
+                              ELSE jsonb_set(data, array[%2%], '{}')
+                          END,
+                          array[%2%, %3%], %4%::jsonb)) AS writer,
+                      jsonb_each_text(writer.value) AS detail
+                  WHERE account_id = %1%
+              ) <= (
+                  SELECT
+                      CASE WHEN setting_value ~ '^[0-9]+$' THEN
+                          CASE WHEN setting_value::numeric
+                                  <= 9223372036854775807
+                              THEN setting_value::bigint
+                          END
+                      END
+                  FROM setting
+                  WHERE setting_key = '%5%'
+              ),
+              true))")
+            % account_id % writer % key % value
+            % iroha::ametsuchi::kMaxAccountDetailsSizeKey)
+        .str();
+  }
 }  // namespace
 
 namespace iroha {
@@ -778,6 +821,7 @@ namespace iroha {
                               ELSE false END
                       ELSE false END
               ),
+              details_size_ok AS (%s),
               )")
             % checkAccountRolePermission(Role::kSetDetail, ":creator")
             % checkAccountGrantablePermission(
@@ -788,10 +832,14 @@ namespace iroha {
                                  Role::kGetAllAccDetail,
                                  Role::kGetDomainAccDetail,
                                  ":creator_domain",
-                                 ":target_domain"))
+                                 ":target_domain")
+            % checkAccountDetailsSize(
+                  ":target", ":creator", ":key", ":new_value"))
                .str(),
-           R"( AND (SELECT * FROM has_perm))",
-           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+           R"( AND (SELECT * FROM has_perm)
+               AND (SELECT * FROM details_size_ok))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2
+               WHEN NOT (SELECT * FROM details_size_ok) THEN 9 )"});
 
       create_account_statements_ =
           makeCommandStatements(
@@ -1188,13 +1236,17 @@ namespace iroha {
                                WHEN (SELECT * FROM has_role_perm) THEN true
                                ELSE false END
               ),
+              details_size_ok AS (%s),
               )")
             % checkAccountRolePermission(Role::kSetDetail, ":creator")
             % checkAccountGrantablePermission(
-                  Grantable::kSetMyAccountDetail, ":creator", ":target"))
+                  Grantable::kSetMyAccountDetail, ":creator", ":target")
+            % checkAccountDetailsSize(":target", ":creator", ":key", ":value"))
                .str(),
-           R"( AND (SELECT * FROM has_perm))",
-           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+           R"( AND (SELECT * FROM has_perm)
+               AND (SELECT * FROM details_size_ok))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2
+               WHEN NOT (SELECT * FROM details_size_ok) THEN 9 )"});
 
       remove_sync_peer_statements_ = makeCommandStatements(
           sql_,
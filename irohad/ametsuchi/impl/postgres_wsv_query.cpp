@@ -108,6 +108,21 @@ namespace iroha {
       return count("domain");
     }
 
+    iroha::expected::Result<size_t, std::string>
+    PostgresWsvQuery::countAccounts() {
+      return count("account");
+    }
+
+    iroha::expected::Result<size_t, std::string>
+    PostgresWsvQuery::countAssets() {
+      return count("asset");
+    }
+
+    iroha::expected::Result<size_t, std::string>
+    PostgresWsvQuery::countRoles() {
+      return count("role");
+    }
+
     iroha::expected::Result<size_t, std::string>
     PostgresWsvQuery::countTransactions() {
       return count("tx_positions", "DISTINCT hash");
@@ -5,6 +5,9 @@
 
 #include "ametsuchi/impl/rocksdb_command_executor.hpp"
 
+#include <limits>
+#include <system_error>
+
 #include <fmt/core.h>
 #include <rocksdb/utilities/transaction.h>
 #include <boost/algorithm/string.hpp>
@@ -49,6 +52,59 @@ using shared_model::interface::permissions::Role;
 using shared_model::interface::GrantablePermissionSet;
 using shared_model::interface::RolePermissionSet;
 
+namespace {
+  /**
+   * Calculates total size of keys and values of the account details as it
+   * would be after the given detail is set.
+   * @param old_detail_size - size of the key and value of the detail which is
+   * overwritten, zero for a new detail
+   */
+  expected::Result<uint64_t, DbError> accountDetailsSizeAfterSet(
+      RocksDbCommon &common,
+      std::string_view account_name,
+      std::string_view domain_id,
+      uint64_t old_detail_size,
+      std::string_view key,
+      std::string_view value) {
+    RDB_TRY_GET_VALUE(details_size,
+                      accountDetailsSize(common, account_name, domain_id));
+    return details_size - old_detail_size + key.size() + value.size();
+  }
+
+  /**
+   * Checks that the account details size does not exceed
+   * MaxAccountDetailsSize setting, if it is set.
+   */
+  RocksDbCommandExecutor::ExecutionResult checkAccountDetailsSize(
+      RocksDbCommon &common, uint64_t details_size) {
+    auto status = common.get(RocksDBPort::ColumnFamilyType::kWsv,
+                             fmtstrings::kSetting,
+                             iroha::ametsuchi::kMaxAccountDetailsSizeKey);
+    RDB_ERROR_CHECK(canExist(
+        status, [&] { return fmt::format("Max account details size key"); }));
+
+    if (status.ok()) {
+      // values which are not decimal numbers representable as a signed 64 bit
+      // integer are ignored, same as in the postgres executor
+      uint64_t max_details_size = 0ull;
+      auto const [end, ec] = common.decode(max_details_size);
+      if (ec != std::errc{}
+          or end != common.valueBuffer().data() + common.valueBuffer().size()
+          or max_details_size
+              > static_cast<uint64_t>(std::numeric_limits<int64_t>::max()))
+        return {};
+
+      if (details_size > max_details_size)
+        return makeError<void>(
+            RocksDbCommandExecutor::ErrorCodes::kAccountDetailsSizeExceeded,
+            "Account details size {} exceeds {}",
+            details_size,
+            max_details_size);
+    }
+    return {};
+  }
+}  // namespace
+
 RocksDbCommandExecutor::RocksDbCommandExecutor(
     std::shared_ptr<RocksDBContext> db_context,
     std::shared_ptr<shared_model::interface::PermissionToString> perm_converter,
@@ -485,10 +541,26 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
             !creator_account_id.empty() ? creator_account_id : "genesis",
             command.key()));
 
+    RDB_TRY_GET_VALUE(
+        details_size,
+        accountDetailsSizeAfterSet(
+            common,
+            account_name,
+            domain_id,
+            opt_detail ? command.key().size() + opt_detail->size() : 0ull,
+            command.key(),
+            command.value()));
+    if (do_validation)
+      RDB_ERROR_CHECK(checkAccountDetailsSize(common, details_size));
+
     common.valueBuffer().assign(command.value());
     RDB_ERROR_CHECK(forAccountDetail<kDbOperation::kPut>(
         common, account_name, domain_id, creator_id, command.key()));
 
+    common.encode(details_size);
+    RDB_ERROR_CHECK(forAccountDetailsSize<kDbOperation::kPut>(
+        common, account_name, domain_id));
+
     if (!opt_detail) {
       RDB_TRY_GET_VALUE(
           opt_acc_details_count,
@@ -547,6 +619,12 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
         common, account_name, domain_id));
 
+  RDB_TRY_GET_VALUE(
+      opt_accounts_count,
+      forAccountsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+  common.encode((opt_accounts_count ? *opt_accounts_count : 0ull) + 1ull);
+  RDB_ERROR_CHECK(forAccountsTotalCount<kDbOperation::kPut>(common));
+
   common.valueBuffer() = "";
   RDB_ERROR_CHECK(forSignatory<kDbOperation::kPut>(
       common,
@@ -585,6 +663,12 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
         common, domain_id));
   }
 
+  RDB_TRY_GET_VALUE(
+      opt_assets_count,
+      forAssetsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+  common.encode((opt_assets_count ? *opt_assets_count : 0ull) + 1ull);
+  RDB_ERROR_CHECK(forAssetsTotalCount<kDbOperation::kPut>(common));
+
   common.encode(command.precision());
   RDB_ERROR_CHECK(forAsset<kDbOperation::kPut>(common, asset_name, domain_id));
 
@@ -942,6 +1026,18 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
           !creator_account_id.empty() ? creator_account_id : "genesis",
           command.key()));
 
+  RDB_TRY_GET_VALUE(
+      details_size,
+      accountDetailsSizeAfterSet(
+          common,
+          account_name,
+          domain_id,
+          opt_detail ? command.key().size() + opt_detail->size() : 0ull,
+          command.key(),
+          command.value()));
+  if (do_validation)
+    RDB_ERROR_CHECK(checkAccountDetailsSize(common, details_size));
+
   common.valueBuffer().assign(command.value());
   RDB_ERROR_CHECK(forAccountDetail<kDbOperation::kPut>(
       common,
@@ -950,6 +1046,10 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
       !creator_account_id.empty() ? creator_account_id : "genesis",
       command.key()));
 
+  common.encode(details_size);
+  RDB_ERROR_CHECK(
+      forAccountDetailsSize<kDbOperation::kPut>(common, account_name, domain_id));
+
   if (!opt_detail) {
     RDB_TRY_GET_VALUE(
         opt_acc_details_count,
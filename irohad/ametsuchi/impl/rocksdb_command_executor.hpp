@@ -65,6 +65,7 @@ namespace iroha::ametsuchi {
       kCountNotEnough = 5,
       kNotEnoughAssets = 6,
       kIncorrectBalance = 7,
+      kAccountDetailsSizeExceeded = 9,
       kException = 1002,
       kNoImplementation = 1005,
       kPermissionIsAlreadySet = 1007,
@@ -66,6 +66,10 @@
  *                |         +-<role_2, value:permissions bitfield>
  *                |         +-<role_3, value:permissions bitfield>
  *                |
+ *                +-|ACCOUNTS|-+-<total_count, value>
+ *                |
+ *                +-|ASSETS|-+-<total_count, value>
+ *                |
  *                +-|TRANSACTIONS|-+-|ACCOUNTS|-+-<account_1>-+-|POSITION|-+-<height_index, value:tx_hash_1>
  *                |                |            |             |            +-<height_index, value:tx_hash_2>
  *                |                |            |             |            +-<height_index, value:tx_hash_3>
@@ -100,6 +104,7 @@
  *                |          |                                  +-|OPTIONS|-+-<quorum>
  *                |          |                                  |           +-<asset_size>
  *                |          |                                  |           +-<total, value: count>
+ *                |          |                                  |           +-<details_size, value: bytes>
  *                |          |                                  |
  *                |          |                                  +-|DETAILS|-+-<writer>-<key, value>
  *                |          |                                  |
@@ -189,6 +194,7 @@
  * ### F_TOTAL COUNT ##       V       ###
  * ### F_VERSION     ##       v       ###
  * ### F_NEXT_ID     ##       X       ###
+ * ### F_DETAIL SIZE ##       z       ###
  * ######################################
  *
  * ######################################
@@ -239,6 +245,7 @@
 #define RDB_F_TOTAL_COUNT "V"
 #define RDB_F_VERSION "v"
 #define RDB_F_NEXT_ID "X"
+#define RDB_F_DETAILS_SIZE "z"
 
 #define RDB_PATH_DOMAIN RDB_ROOT /**/ RDB_WSV /**/ RDB_DOMAIN /**/ RDB_XXX
 #define RDB_PATH_ACCOUNT RDB_PATH_DOMAIN /**/ RDB_ACCOUNTS /**/ RDB_XXX
@@ -285,6 +292,10 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kPathSignatories{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_SIGNATORIES)};
 
+  // no param
+  static auto constexpr kPathDomains{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_DOMAIN)};
+
   // no param
   static auto constexpr kPathRoles{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ROLES)};
@@ -502,10 +513,22 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kDomainsTotalCount{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_DOMAIN /**/ RDB_F_TOTAL_COUNT)};
 
+  // ➡️ accounts total count
+  static auto constexpr kAccountsTotalCount{FMT_STRING(
+      RDB_ROOT /**/ RDB_WSV /**/ RDB_ACCOUNTS /**/ RDB_F_TOTAL_COUNT)};
+
+  // ➡️ asset definitions total count
+  static auto constexpr kAssetsTotalCount{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSETS /**/ RDB_F_TOTAL_COUNT)};
+
   // domain_id/account_name/ ➡️ value
   static auto constexpr kAccountDetailsCount{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_OPTIONS /**/ RDB_F_TOTAL_COUNT)};
 
+  // domain_id/account_name/ ➡️ size
+  static auto constexpr kAccountDetailsSize{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_OPTIONS /**/ RDB_F_DETAILS_SIZE)};
+
   // ➡️ value
   static auto constexpr kStoreVersion{
       FMT_STRING(RDB_ROOT /**/ RDB_STORE /**/ RDB_F_VERSION)};
@@ -1501,6 +1524,28 @@ namespace iroha::ametsuchi {
                                       account);
   }
 
+  /**
+   * Access to total size of keys and values of account details.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param domain id
+   * @param account name
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<uint64_t>, DbError>
+  forAccountDetailsSize(RocksDbCommon &common,
+                        std::string_view account,
+                        std::string_view domain) {
+    return dbCall<uint64_t, kOp, kSc>(common,
+                                      RocksDBPort::ColumnFamilyType::kWsv,
+                                      fmtstrings::kAccountDetailsSize,
+                                      domain,
+                                      account);
+  }
+
   /**
    * Access to store version.
    * @tparam kOp @see kDbOperation
@@ -1640,6 +1685,38 @@ namespace iroha::ametsuchi {
                                       fmtstrings::kDomainsTotalCount);
   }
 
+  /**
+   * Access to accounts total count.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<uint64_t>, DbError>
+  forAccountsTotalCount(RocksDbCommon &common) {
+    return dbCall<uint64_t, kOp, kSc>(common,
+                                      RocksDBPort::ColumnFamilyType::kWsv,
+                                      fmtstrings::kAccountsTotalCount);
+  }
+
+  /**
+   * Access to asset definitions total count.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<uint64_t>, DbError>
+  forAssetsTotalCount(RocksDbCommon &common) {
+    return dbCall<uint64_t, kOp, kSc>(common,
+                                      RocksDBPort::ColumnFamilyType::kWsv,
+                                      fmtstrings::kAssetsTotalCount);
+  }
+
   /**
    * Access to account folder
    * @tparam kOp @see kDbOperation
@@ -2356,6 +2433,125 @@ namespace iroha::ametsuchi {
     return makeError<void>(DbErrorCode::kErrorNoPermissions, "No permissions.");
   }
 
+  /**
+   * Get total size of keys and values of all the account details. WSV created
+   * before the size was stored does not have it, so the details of the account
+   * are enumerated then.
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @return total size in bytes
+   */
+  inline expected::Result<uint64_t, DbError> accountDetailsSize(
+      RocksDbCommon &common, std::string_view account, std::string_view domain) {
+    RDB_TRY_GET_VALUE(
+        opt_size,
+        forAccountDetailsSize<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account, domain));
+    if (opt_size)
+      return *opt_size;
+
+    uint64_t size = 0ull;
+    auto status = ametsuchi::enumerateKeysAndValues(
+        common,
+        [&](auto path, auto detail_value) {
+          auto const &[writer, _, key] =
+              staticSplitId<3>(path.ToStringView(), fmtstrings::kDelimiter);
+          size += key.size() + detail_value.size();
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathAccountDetail,
+        domain,
+        account);
+    RDB_ERROR_CHECK(canExist(status, [&] {
+      return fmt::format("Enumerate details of account {}@{}", account, domain);
+    }));
+    return size;
+  }
+
+  /**
+   * Count the keys of the domains subtree which match the predicate. Used to
+   * restore the total counts which are absent in WSV created before they were
+   * introduced.
+   * @param common @see RocksDbCommon
+   * @param pred is called with the part of the key following the domains path
+   * @return number of matched keys
+   */
+  template <typename F>
+  inline expected::Result<uint64_t, DbError> countDomainsSubtreeKeys(
+      RocksDbCommon &common, F &&pred) {
+    uint64_t count = 0ull;
+    auto status = common.enumerate(
+        [&](auto const &it, auto const prefix_size) {
+          auto const key = it->key();
+          if (pred(std::string_view(key.data() + prefix_size,
+                                    key.size() - prefix_size)))
+            ++count;
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathDomains);
+    RDB_ERROR_CHECK(
+        canExist(status, [] { return std::string{"Enumerate domains"}; }));
+    return count;
+  }
+
+  /**
+   * Store the accounts and asset definitions total counts if they are absent,
+   * as in WSV created before the counts were introduced. Accounts are counted
+   * by their quorum entries and asset definitions of all domains are
+   * enumerated, so it can take a while on a large WSV.
+   * @param common @see RocksDbCommon
+   * @return error if counting or storing fails
+   */
+  inline expected::Result<void, DbError> restoreTotalCounts(
+      RocksDbCommon &common) {
+    RDB_TRY_GET_VALUE(
+        opt_accounts_count,
+        forAccountsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+    if (not opt_accounts_count) {
+      // /<domain>/a/<account>/Oq
+      static constexpr std::string_view kQuorumSuffix{
+          RDB_DELIMITER RDB_OPTIONS RDB_F_QUORUM};
+      RDB_TRY_GET_VALUE(
+          accounts_count,
+          countDomainsSubtreeKeys(common, [](std::string_view key) {
+            return key.size() >= kQuorumSuffix.size()
+                && key.substr(key.size() - kQuorumSuffix.size())
+                == kQuorumSuffix;
+          }));
+      common.encode(accounts_count);
+      RDB_ERROR_CHECK(forAccountsTotalCount<kDbOperation::kPut>(common));
+    }
+
+    RDB_TRY_GET_VALUE(
+        opt_assets_count,
+        forAssetsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+    if (not opt_assets_count) {
+      // /<domain>/x/<asset>/
+      static constexpr std::string_view kAssetsPath{
+          RDB_DELIMITER RDB_ASSETS RDB_DELIMITER};
+      RDB_TRY_GET_VALUE(
+          assets_count,
+          countDomainsSubtreeKeys(common, [](std::string_view key) {
+            auto const domain_end =
+                key.find(fmtstrings::kDelimiter, fmtstrings::kDelimiterSize);
+            if (domain_end == std::string_view::npos)
+              return false;
+            key.remove_prefix(domain_end);
+            return key.size() > kAssetsPath.size()
+                && key.substr(0, kAssetsPath.size()) == kAssetsPath
+                && key.find(fmtstrings::kDelimiter, kAssetsPath.size())
+                == key.size() - fmtstrings::kDelimiterSize;
+          }));
+      common.encode(assets_count);
+      RDB_ERROR_CHECK(forAssetsTotalCount<kDbOperation::kPut>(common));
+    }
+
+    return {};
+  }
+
   struct PaginationContext {
     struct FirstEntry {
       std::string writer_from;
@@ -233,6 +233,42 @@ namespace iroha::ametsuchi {
     return opt_count ? *opt_count : 0ull;
   }
 
+  iroha::expected::Result<size_t, std::string>
+  RocksDBWsvQuery::countAccounts() {
+    RocksDbCommon common(db_context_);
+    RDB_TRY_GET_VALUE_OR_STR_ERR(
+        opt_count,
+        forAccountsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+
+    return opt_count ? *opt_count : 0ull;
+  }
+
+  iroha::expected::Result<size_t, std::string> RocksDBWsvQuery::countAssets() {
+    RocksDbCommon common(db_context_);
+    RDB_TRY_GET_VALUE_OR_STR_ERR(
+        opt_count,
+        forAssetsTotalCount<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+
+    return opt_count ? *opt_count : 0ull;
+  }
+
+  iroha::expected::Result<size_t, std::string> RocksDBWsvQuery::countRoles() {
+    RocksDbCommon common(db_context_);
+    size_t count = 0ull;
+    auto const status = enumerateKeys(
+        common,
+        [&](auto const &) {
+          ++count;
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathRoles);
+    RDB_ERROR_CHECK_TO_STR(
+        canExist(status, [&]() { return std::string{"Enumerate roles"}; }));
+
+    return count;
+  }
+
   iroha::expected::Result<size_t, std::string>
   RocksDBWsvQuery::countTransactions() {
     RocksDbCommon common(db_context_);
@@ -36,6 +36,9 @@ namespace iroha {
       iroha::expected::Result<size_t, std::string> countPeers(
           bool syncing_peers) override;
       iroha::expected::Result<size_t, std::string> countDomains() override;
+      iroha::expected::Result<size_t, std::string> countAccounts() override;
+      iroha::expected::Result<size_t, std::string> countAssets() override;
+      iroha::expected::Result<size_t, std::string> countRoles() override;
       iroha::expected::Result<size_t, std::string> countTransactions() override;
 
      private:
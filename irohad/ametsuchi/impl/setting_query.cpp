@@ -7,3 +7,5 @@
 
 const shared_model::interface::types::SettingKeyType
     iroha::ametsuchi::kMaxDescriptionSizeKey = "MaxDescriptionSize";
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAccountDetailsSizeKey = "MaxAccountDetailsSize";
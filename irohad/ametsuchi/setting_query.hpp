@@ -28,6 +28,8 @@ namespace iroha {
 
     extern const shared_model::interface::types::SettingKeyType
         kMaxDescriptionSizeKey;
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAccountDetailsSizeKey;
   }  // namespace ametsuchi
 }  // namespace iroha
 
@@ -63,6 +63,24 @@ namespace iroha {
        */
       virtual iroha::expected::Result<size_t, std::string> countDomains() = 0;
 
+      /**
+       * @brief Fetch number of accounts in ledger
+       * @return number of accounts in ledger
+       */
+      virtual iroha::expected::Result<size_t, std::string> countAccounts() = 0;
+
+      /**
+       * @brief Fetch number of asset definitions in ledger
+       * @return number of asset definitions in ledger
+       */
+      virtual iroha::expected::Result<size_t, std::string> countAssets() = 0;
+
+      /**
+       * @brief Fetch number of roles in ledger
+       * @return number of roles in ledger
+       */
+      virtual iroha::expected::Result<size_t, std::string> countRoles() = 0;
+
       /**
        * @brief Fetch number of valid transactions in ledger
        * @return number of transactions in ledger
@@ -7,6 +7,7 @@
 
 #include <fmt/core.h>
 #include <cassert>
+#include <string>
 #include "CivetServer.h"
 
 #include "common/mem_operations.hpp"
@@ -14,14 +15,21 @@
 
 namespace iroha::network {
   std::string HttpServer::Options::toString() const {
-    return fmt::format("Options [ports:{}, request_timeout_ms: {}]",
-                       ports,
-                       request_timeout_ms);
+    return fmt::format(
+        "Options [ports:{}, request_timeout_ms: {}, "
+        "access_control_allow_origin: {}]",
+        ports,
+        request_timeout_ms,
+        access_control_allow_origin);
   }
 
-  HttpRequestResponse::HttpRequestResponse(mg_connection *connection,
-                                           mg_request_info const *request_info)
-      : connection_(connection), request_info_(request_info) {}
+  HttpRequestResponse::HttpRequestResponse(
+      mg_connection *connection,
+      mg_request_info const *request_info,
+      std::string_view access_control_allow_origin)
+      : connection_(connection),
+        request_info_(request_info),
+        access_control_allow_origin_(access_control_allow_origin) {}
 
   std::optional<int> HttpRequestResponse::init() {
     if (0 == strcmp(request_info_->request_method, "GET")) {
@@ -45,12 +53,24 @@ namespace iroha::network {
     return std::nullopt;
   }
 
-  bool HttpRequestResponse::setJsonResponse(std::string_view data) {
+  bool HttpRequestResponse::setJsonResponse(std::string_view data,
+                                            int status_code) {
     if (!method_)
       return false;
 
-    mg_send_http_ok(
-        connection_, "application/json; charset=utf-8", (long long)data.size());
+    mg_printf(connection_,
+              "HTTP/1.1 %d %s\r\n"
+              "Content-Type: application/json; charset=utf-8\r\n"
+              "Content-Length: %zu\r\n",
+              status_code,
+              mg_get_response_code_text(connection_, status_code),
+              data.size());
+    if (not access_control_allow_origin_.empty())
+      mg_printf(connection_,
+                "Access-Control-Allow-Origin: %.*s\r\n",
+                (int)access_control_allow_origin_.size(),
+                access_control_allow_origin_.data());
+    mg_printf(connection_, "\r\n");
     mg_write(connection_, data.data(), data.size());
     return true;
   }
@@ -126,7 +146,8 @@ namespace iroha::network {
       return;
     }
 
-    handlers_.emplace_back(std::move(handler), logger_);
+    handlers_.emplace_back(
+        std::move(handler), logger_, options_.access_control_allow_origin);
     mg_set_request_handler(
         context_,
         uri.data(),
@@ -134,7 +155,9 @@ namespace iroha::network {
           assert(nullptr != cbdata);
           HandlerData &handler = *(HandlerData *)cbdata;
 
-          HttpRequestResponse req_res(conn, mg_get_request_info(conn));
+          HttpRequestResponse req_res(conn,
+                                      mg_get_request_info(conn),
+                                      handler.access_control_allow_origin);
           if (auto code = req_res.init(); code) {
             handler.logger->error(
                 "Init HttpRequestResponse failed with code: {}", *code);
@@ -28,14 +28,16 @@ namespace iroha::network {
   class HttpRequestResponse {
     mg_connection *connection_;
     mg_request_info const *request_info_;
+    std::string_view access_control_allow_origin_;
     std::optional<eMethodType> method_;
 
    public:
     HttpRequestResponse(mg_connection *connection,
-                        mg_request_info const *request_info);
+                        mg_request_info const *request_info,
+                        std::string_view access_control_allow_origin = {});
     std::optional<int> init();
 
-    bool setJsonResponse(std::string_view data);
+    bool setJsonResponse(std::string_view data, int status_code = 200);
 
     eMethodType getMethodType() const;
   };
@@ -49,14 +51,20 @@ namespace iroha::network {
     struct HandlerData {
       HandlerCallback callback;
       logger::LoggerPtr logger;
-
-      HandlerData(HandlerCallback c, logger::LoggerPtr l)
-          : callback(std::move(c)), logger(std::move(l)) {}
+      std::string_view access_control_allow_origin;
+
+      HandlerData(HandlerCallback c,
+                  logger::LoggerPtr l,
+                  std::string_view allow_origin)
+          : callback(std::move(c)),
+            logger(std::move(l)),
+            access_control_allow_origin(allow_origin) {}
     };
 
     struct Options {
       std::string ports;               // ex. "50500,50501,50502"
       std::string request_timeout_ms;  // default: 10000
+      std::string access_control_allow_origin;  // default: no CORS header
 
       std::string toString() const;
     };
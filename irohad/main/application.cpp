@@ -6,6 +6,7 @@
 #include "main/application.hpp"
 
 #include <civetweb.h>
+#include <fmt/core.h>
 #include <rapidjson/document.h>
 #include <rapidjson/stringbuffer.h>
 #include <rapidjson/writer.h>
@@ -27,6 +28,7 @@
 #include "backend/protobuf/proto_tx_status_factory.hpp"
 #include "common/bind.hpp"
 #include "common/files.hpp"
+#include "common/irohad_version.hpp"
 #include "common/result_try.hpp"
 #include "consensus/yac/consensus_outcome_type.hpp"
 #include "consensus/yac/consistency_model.hpp"
@@ -66,6 +68,7 @@
 #include "torii/impl/command_service_transport_grpc.hpp"
 #include "torii/processor/query_processor_impl.hpp"
 #include "torii/processor/transaction_processor_impl.hpp"
+#include "torii/query_response_cache.hpp"
 #include "torii/query_service.hpp"
 #include "torii/tls_params.hpp"
 #include "validation/impl/chain_validator_impl.hpp"
@@ -248,6 +251,8 @@ Irohad::RunResult Irohad::initNodeStatus() {
                     new_status.memory_consumption;
               if (new_status.last_round)
                 status.status.last_round = new_status.last_round;
+              if (new_status.queue_size)
+                status.status.queue_size = new_status.queue_size;
 
               status.serialized_status.Clear();
             });
@@ -299,6 +304,8 @@ Irohad::RunResult Irohad::initHttpServer() {
   options.ports = config_.healthcheck_port
       ? std::to_string(*config_.healthcheck_port)
       : iroha::network::kHealthcheckDefaultPort;
+  if (config_.http_allow_origin)
+    options.access_control_allow_origin = *config_.http_allow_origin;
 
   http_server_ = std::make_unique<iroha::network::HttpServer>(
       std::move(options), log_manager_->getChild("HTTP server")->getLogger());
@@ -358,6 +365,11 @@ Irohad::RunResult Irohad::initHttpServer() {
                            status.status.is_healthy.has_value(),
                            *status.status.is_healthy);
 
+                writer.Key("queue_size");
+                setOptUInt64(writer,
+                             status.status.queue_size.has_value(),
+                             *status.status.queue_size);
+
                 writer.EndObject();
               }
               req_res.setJsonResponse(
@@ -365,6 +377,51 @@ Irohad::RunResult Irohad::initHttpServer() {
                                    status.serialized_status.GetLength()));
             });
       });
+
+  http_server_->registerHandler(
+      "/ready",
+      [status_sub(iroha_status_subscription_)](
+          iroha::network::HttpRequestResponse &req_res) {
+        auto const ready = status_sub->get().exclusiveAccess(
+            [](iroha::IrohaStoredStatus &status) {
+              return iroha::isReady(status.status);
+            });
+        if (ready)
+          req_res.setJsonResponse(R"({"ready":true})");
+        else
+          req_res.setJsonResponse(R"({"ready":false})", 503);
+      });
+
+  {
+    using namespace rapidjson;
+    StringBuffer version_info;
+    Writer<StringBuffer> writer(version_info);
+    auto const version = iroha::getIrohadVersion();
+
+    writer.StartObject();
+    writer.Key("version");
+    writer.String(
+        fmt::format("{}.{}.{}", version.major, version.minor, version.patch)
+            .c_str());
+    writer.Key("git_version");
+    writer.String(iroha::kGitPrettyVersion);
+    writer.Key("storage");
+    writer.String(config_.database_config
+                          and config_.database_config->type == kDbTypeRocksdb
+                      ? kDbTypeRocksdb.c_str()
+                      : kDbTypePostgres.c_str());
+    writer.Key("burrow");
+    writer.Bool(vm_caller_.has_value());
+    writer.EndObject();
+
+    http_server_->registerHandler(
+        "/version",
+        [version_info(std::string(version_info.GetString(),
+                                  version_info.GetLength()))](
+            iroha::network::HttpRequestResponse &req_res) {
+          req_res.setJsonResponse(version_info);
+        });
+  }
   return {};
 }
 
@@ -386,6 +443,9 @@ Irohad::RunResult Irohad::initStorage(
     auto process_block =
         [this](std::shared_ptr<shared_model::interface::Block const> block) {
           iroha::getSubscription()->notify(EventTypes::kOnBlock, block);
+          if (query_response_cache_) {
+            query_response_cache_->invalidate();
+          }
           if (ordering_init and tx_processor and pending_txs_storage_) {
             ordering_init->processCommittedBlock(block);
             tx_processor->processCommit(block);
@@ -744,6 +804,8 @@ Irohad::RunResult Irohad::initOrderingGate() {
 
   ordering_gate = ordering_init->initOrderingGate(
       config_.max_proposal_size,
+      config_.max_queue_size ? std::optional<size_t>(*config_.max_queue_size)
+                             : std::nullopt,
       std::chrono::milliseconds(
           config_.proposal_creation_timeout.value_or(kMaxRoundsDelayDefault)
           * kProposalDelayMultiplier),
@@ -1020,13 +1082,22 @@ Irohad::RunResult Irohad::initQueryService() {
       query_response_factory_,
       query_service_log_manager->getChild("Processor")->getLogger());
 
+  if (config_.query_response_cache_size
+      and *config_.query_response_cache_size > 0) {
+    query_response_cache_ = std::make_shared<::torii::QueryResponseCache>(
+        *config_.query_response_cache_size);
+    log_->info("[Init] => query response cache of {} entries",
+               *config_.query_response_cache_size);
+  }
+
   assert(iroha_status_subscription_);
   query_service = std::make_shared<::torii::QueryService>(
       query_processor,
       query_factory,
       blocks_query_factory,
       query_service_log_manager->getLogger(),
-      iroha_status_subscription_);
+      iroha_status_subscription_,
+      query_response_cache_);
 
   log_->info("[Init] => query service");
   return {};
@@ -1060,6 +1131,7 @@ namespace {
     context.subscription->notify(
         EventTypes::kOnConsensusGateEvent,
         ::torii::CommandServiceTransportGrpc::ConsensusGateEvent{});
+    context.subscription->notify(EventTypes::kOnOutcome, object);
     context.log->info("~~~~~~~~~| PROPOSAL ^_^ |~~~~~~~~~ ");
     auto event = context.synchronizer->processOutcome(std::move(object));
     if (not event) {
@@ -1079,6 +1151,7 @@ namespace {
       return processGateObject(std::move(context), *maybe_object);
     }
     context.ordering_init->processRoundSwitch(round_switch);
+    context.subscription->notify(EventTypes::kOnRoundSwitch, round_switch);
   }
 }  // namespace
 
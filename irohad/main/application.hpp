@@ -88,6 +88,7 @@ namespace iroha {
     class CommandService;
     class CommandServiceTransportGrpc;
     class QueryService;
+    class QueryResponseCache;
     class TransactionProcessor;
     struct TlsParams;
   }  // namespace torii
@@ -390,6 +391,7 @@ class Irohad {
       iroha_status_subscription_;
 
   // query service
+  std::shared_ptr<iroha::torii::QueryResponseCache> query_response_cache_;
   std::shared_ptr<iroha::torii::QueryService> query_service;
 
   // Http server
@@ -28,6 +28,9 @@ namespace {
   /// indexes to permutations for corresponding rounds
   enum RoundType { kCurrentRound, kNextRound, kCount };
 
+  /// number of proposals stored in the ordering service
+  constexpr size_t kNumberOfProposals = 3;
+
   template <RoundType V>
   using RoundTypeConstant = std::integral_constant<RoundType, V>;
 }  // namespace
@@ -97,6 +100,7 @@ auto OnDemandOrderingInit::createGate(
 
 auto OnDemandOrderingInit::createService(
     size_t max_number_of_transactions,
+    std::optional<size_t> max_queue_size,
     std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
         proposal_factory,
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_cache,
@@ -105,13 +109,16 @@ auto OnDemandOrderingInit::createService(
       max_number_of_transactions,
       std::move(proposal_factory),
       std::move(tx_cache),
-      ordering_log_manager->getChild("Service")->getLogger());
+      ordering_log_manager->getChild("Service")->getLogger(),
+      kNumberOfProposals,
+      max_queue_size);
   return ordering_service_;
 }
 
 std::shared_ptr<iroha::network::OrderingGate>
 OnDemandOrderingInit::initOrderingGate(
     size_t max_number_of_transactions,
+    std::optional<size_t> max_queue_size,
     std::chrono::milliseconds delay,
     std::shared_ptr<transport::OnDemandOsServerGrpc::TransportFactoryType>
         transaction_factory,
@@ -130,6 +137,7 @@ OnDemandOrderingInit::initOrderingGate(
   std::shared_ptr<OnDemandOrderingService> ordering_service;
   if (!syncing_mode) {
     ordering_service = createService(max_number_of_transactions,
+                                     max_queue_size,
                                      proposal_factory,
                                      tx_cache,
                                      ordering_log_manager);
@@ -158,12 +166,15 @@ OnDemandOrderingInit::initOrderingGate(
       iroha::SubscriptionEngineHandlers::kMetrics,
       std::max(delay * 4, std::chrono::milliseconds(1000ull)),
       [round(consensus::Round(0ull, 0ull)),
-       wgate(utils::make_weak(ordering_gate_))]() mutable {
+       wgate(utils::make_weak(ordering_gate_)),
+       wservice(utils::make_weak(ordering_service))]() mutable {
         if (auto gate = wgate.lock()) {
           auto const new_round = gate->getRound();
           iroha::IrohaStatus status;
           status.is_healthy = (new_round != round);
           status.last_round = new_round;
+          if (auto service = wservice.lock())
+            status.queue_size = service->queueSize();
           iroha::getSubscription()->notify(iroha::EventTypes::kOnIrohaStatus,
                                            status);
           round = new_round;
@@ -103,6 +103,7 @@ namespace iroha::ordering {
      */
     auto createService(
         size_t max_number_of_transactions,
+        std::optional<size_t> max_queue_size,
         std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
             proposal_factory,
         std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
@@ -118,6 +119,8 @@ namespace iroha::ordering {
      *
      * @param max_number_of_transactions maximum number of transactions in a
      * proposal
+     * @param max_queue_size maximum number of transactions waiting in the
+     * ordering service, unlimited if not set
      * @param delay timeout for ordering service response on proposal request
      * @param transaction_factory transport factory for transactions required
      * by ordering service network endpoint
@@ -132,6 +135,7 @@ namespace iroha::ordering {
      */
     std::shared_ptr<network::OrderingGate> initOrderingGate(
         size_t max_number_of_transactions,
+        std::optional<size_t> max_queue_size,
         std::chrono::milliseconds delay,
         std::shared_ptr<shared_model::interface::AbstractTransportFactory<
             shared_model::interface::Transaction,
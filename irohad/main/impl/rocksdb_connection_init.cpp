@@ -105,7 +105,8 @@ RdbConnectionInit::prepareWorkingDatabase(
     return port;
   }
 
-  return isSchemaCompatible(common, options) | [port](bool is_compatible)
+  return isSchemaCompatible(common, options) |
+             [port, &common](bool is_compatible)
              -> iroha::expected::Result<std::shared_ptr<RocksDBPort>,
                                         std::string> {
     if (not is_compatible) {
@@ -113,6 +114,11 @@ RdbConnectionInit::prepareWorkingDatabase(
              "Either overwrite the ledger or use a compatible binary "
              "version.";
     }
+    // WSV created before the total counts were introduced does not have them
+    RDB_ERROR_CHECK_TO_STR(restoreTotalCounts(common));
+    if (auto status = common.commit(); not status.ok())
+      return fmt::format("Storing total counts failed: {}",
+                         status.ToString());
     return port;
   };
 }
@@ -29,13 +29,16 @@ namespace config_members {
   const char *DbPath = "path";
   const char *DbType = "type";
   const char *MaxProposalSize = "max_proposal_size";
+  const char *MaxQueueSize = "max_queue_size";
   const char *ProposalCreationTimeout = "proposal_creation_timeout";
   const char *HealthcheckPort = "healthcheck_port";
+  const char *HttpAllowOrigin = "http_allow_origin";
   const char *VoteDelay = "vote_delay";
   const char *MstSupport = "mst_enable";
   const char *MstExpirationTime = "mst_expiration_time";
   const char *MaxRoundsDelay = "max_rounds_delay";
   const char *StaleStreamMaxRounds = "stale_stream_max_rounds";
+  const char *QueryResponseCacheSize = "query_response_cache_size";
   const char *LogSection = "log";
   const char *LogLevel = "level";
   const char *LogPatternsSection = "patterns";
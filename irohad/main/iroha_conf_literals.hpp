@@ -35,13 +35,16 @@ namespace config_members {
   extern const char *DbPath;
   extern const char *DbType;
   extern const char *MaxProposalSize;
+  extern const char *MaxQueueSize;
   extern const char *ProposalCreationTimeout;
   extern const char *HealthcheckPort;
+  extern const char *HttpAllowOrigin;
   extern const char *VoteDelay;
   extern const char *MstSupport;
   extern const char *MstExpirationTime;
   extern const char *MaxRoundsDelay;
   extern const char *StaleStreamMaxRounds;
+  extern const char *QueryResponseCacheSize;
   extern const char *LogSection;
   extern const char *LogLevel;
   extern const char *LogPatternsSection;
@@ -689,15 +689,19 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig &dest) {
       and getDictChild(DbConfig).loadInto(dest.database_config)
       and (dest.database_config or getDictChild(PgOpt).loadInto(dest.pg_opt))
       and getDictChild(MaxProposalSize).loadInto(dest.max_proposal_size)
+      and getDictChild(MaxQueueSize).loadInto(dest.max_queue_size)
       and getDictChild(ProposalCreationTimeout)
               .loadInto(dest.proposal_creation_timeout)
       and getDictChild(HealthcheckPort).loadInto(dest.healthcheck_port)
+      and getDictChild(HttpAllowOrigin).loadInto(dest.http_allow_origin)
       and getDictChild(VoteDelay).loadInto(dest.vote_delay)
       and getDictChild(MstSupport).loadInto(dest.mst_support)
       and getDictChild(MstExpirationTime).loadInto(dest.mst_expiration_time)
       and getDictChild(MaxRoundsDelay).loadInto(dest.max_round_delay_ms)
       and getDictChild(StaleStreamMaxRounds)
               .loadInto(dest.stale_stream_max_rounds)
+      and getDictChild(QueryResponseCacheSize)
+              .loadInto(dest.query_response_cache_size)
       and getDictChild(LogSection).loadInto(dest.logger_manager)
       and getDictChild(InitialPeers).loadInto(dest.initial_peers)
       and getDictChild(UtilityService).loadInto(dest.utility_service)
@@ -20,8 +20,21 @@ namespace iroha {
     std::optional<consensus::Round> last_round;
     std::optional<bool> is_syncing;
     std::optional<bool> is_healthy;
+    std::optional<uint64_t> queue_size;
   };
 
+  /**
+   * Check if the node is ready to serve clients: it has taken part in
+   * consensus rounds, is healthy and is not synchronizing blocks.
+   * @param status of the node
+   * @return true if the node is ready
+   */
+  inline bool isReady(IrohaStatus const &status) {
+    return status.last_round.has_value() and status.is_syncing.has_value()
+        and not *status.is_syncing and status.is_healthy.has_value()
+        and *status.is_healthy;
+  }
+
   struct IrohaStoredStatus {
     IrohaStatus status;
     rapidjson::StringBuffer serialized_status;
@@ -65,7 +65,10 @@ namespace iroha {
     kOnState,
 
     // TEST
-    kOnTestOperationComplete
+    kOnTestOperationComplete,
+
+    // Ordering
+    kOnDroppedBatches
   };
 
   static constexpr uint32_t kThreadPoolSize = 3u;
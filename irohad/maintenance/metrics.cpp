@@ -9,13 +9,18 @@
 #include <prometheus/exposer.h>
 #include <prometheus/registry.h>
 
+#include <array>
 #include <memory>
 #include <regex>
+#include <variant>
 
 #include "CivetServer.h"  // for CivetCallbacks
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/command.hpp"
+#include "interfaces/commands/create_account.hpp"
+#include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
+#include "interfaces/commands/create_role.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/iroha_internal/block.hpp"
 #include "interfaces/transaction.hpp"
@@ -86,6 +91,28 @@ Metrics::Metrics(std::string const &listen_addr,
   auto &domains_number = domains_number_gauge.Add({});
   domains_number.Set(storage_->getWsvQuery()->countDomains().assumeValue());
 
+  auto &accounts_number_gauge = BuildGauge()
+                                    .Name("number_of_accounts")
+                                    .Help("Total number of accounts in WSV")
+                                    .Register(*registry_);
+  auto &accounts_number = accounts_number_gauge.Add({});
+  accounts_number.Set(storage_->getWsvQuery()->countAccounts().assumeValue());
+
+  auto &assets_number_gauge =
+      BuildGauge()
+          .Name("number_of_asset_definitions")
+          .Help("Total number of asset definitions in WSV")
+          .Register(*registry_);
+  auto &assets_number = assets_number_gauge.Add({});
+  assets_number.Set(storage_->getWsvQuery()->countAssets().assumeValue());
+
+  auto &roles_number_gauge = BuildGauge()
+                                 .Name("number_of_roles")
+                                 .Help("Total number of roles in WSV")
+                                 .Register(*registry_);
+  auto &roles_number = roles_number_gauge.Add({});
+  roles_number.Set(storage_->getWsvQuery()->countRoles().assumeValue());
+
   auto &total_number_of_transactions_gauge =
       BuildGauge()
           .Name("total_number_of_transactions")
@@ -109,6 +136,16 @@ Metrics::Metrics(std::string const &listen_addr,
           .assumeValue();
   number_of_signatures_in_last_block.Set(boost::size(ptopblock->signatures()));
 
+  auto &number_of_transactions_in_last_block_gauge =
+      BuildGauge()
+          .Name("number_of_transactions_in_last_block")
+          .Help("Number of transactions in last block")
+          .Register(*registry_);
+  auto &number_of_transactions_in_last_block =
+      number_of_transactions_in_last_block_gauge.Add({});
+  number_of_transactions_in_last_block.Set(
+      boost::size(ptopblock->transactions()));
+
   block_subscriber_ =
       SubscriberCreator<bool, BlockPtr>::template create<EventTypes::kOnBlock>(
           SubscriptionEngineHandlers::kMetrics,
@@ -121,21 +158,30 @@ Metrics::Metrics(std::string const &listen_addr,
             block_height.Set(pblock->height());
             number_of_signatures_in_last_block.Set(
                 boost::size(pblock->signatures()));
+            number_of_transactions_in_last_block.Set(
+                boost::size(pblock->transactions()));
             total_number_of_transactions.Increment(
                 boost::size(pblock->transactions()));
             logger_->info("total_number_of_transactions {}",
                           total_number_of_transactions.Value());
-            int domains_diff = 0, peers_diff = 0;
+            int domains_diff = 0, peers_diff = 0, accounts_diff = 0,
+                assets_diff = 0, roles_diff = 0;
             using namespace shared_model::interface;
             for (Transaction const &trx : pblock->transactions()) {
               for (Command const &cmd : trx.commands()) {
                 domains_diff += cmd.is<CreateDomain>() ? 1 : 0;
+                accounts_diff += cmd.is<CreateAccount>() ? 1 : 0;
+                assets_diff += cmd.is<CreateAsset>() ? 1 : 0;
+                roles_diff += cmd.is<CreateRole>() ? 1 : 0;
                 peers_diff += cmd.is<AddPeer>() ? 1 : 0;
                 peers_diff -= cmd.is<RemovePeer>() ? 1 : 0;
               }
             }
             number_of_peers.Increment(peers_diff);
             domains_number.Increment(domains_diff);
+            accounts_number.Increment(accounts_diff);
+            assets_number.Increment(assets_diff);
+            roles_number.Increment(roles_diff);
           });
 
   /////////////////////////////
@@ -151,6 +197,26 @@ Metrics::Metrics(std::string const &listen_addr,
                          .Register(*registry_)
                          .Add({});
 
+  auto &last_block_round = BuildGauge()
+                               .Name("last_block_round")
+                               .Help("Block round of the last consensus round")
+                               .Register(*registry_)
+                               .Add({});
+
+  auto &last_reject_round =
+      BuildGauge()
+          .Name("last_reject_round")
+          .Help("Number of reject rounds since the last committed block")
+          .Register(*registry_)
+          .Add({});
+
+  auto &number_of_queued_transactions =
+      BuildGauge()
+          .Name("number_of_queued_transactions")
+          .Help("Number of transactions waiting in the ordering service")
+          .Register(*registry_)
+          .Add({});
+
   iroha_status_subscription_ =
       SubscriberCreator<bool, iroha::IrohaStatus>::template create<
           EventTypes::kOnIrohaStatus>(
@@ -160,6 +226,86 @@ Metrics::Metrics(std::string const &listen_addr,
                 new_status.is_syncing && *new_status.is_syncing ? 1 : 0);
             is_healthy.Set(new_status.is_healthy && *new_status.is_healthy ? 1
                                                                            : 0);
+            if (new_status.last_round) {
+              last_block_round.Set(new_status.last_round->block_round);
+              last_reject_round.Set(new_status.last_round->reject_round);
+            }
+            if (new_status.queue_size)
+              number_of_queued_transactions.Set(*new_status.queue_size);
+          });
+
+  auto &number_of_dropped_transactions =
+      BuildCounter()
+          .Name("number_of_dropped_transactions")
+          .Help("Number of transactions dropped because the ordering service "
+                "queue is full")
+          .Register(*registry_)
+          .Add({});
+
+  dropped_batch_subscriber_ = SubscriberCreator<
+      bool,
+      std::shared_ptr<shared_model::interface::TransactionBatch>>::
+      template create<EventTypes::kOnDroppedBatches>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &,
+              std::shared_ptr<shared_model::interface::TransactionBatch> const
+                  &batch) {
+            number_of_dropped_transactions.Increment(
+                batch->transactions().size());
+          });
+
+  ///////////////////////////// consensus
+  auto &consensus_rounds = BuildCounter()
+                               .Name("consensus_rounds_total")
+                               .Help("Number of started consensus rounds")
+                               .Register(*registry_)
+                               .Add({});
+
+  auto &consensus_votes =
+      BuildCounter()
+          .Name("consensus_votes_received_total")
+          .Help("Number of consensus votes received from peers")
+          .Register(*registry_)
+          .Add({});
+
+  auto &consensus_outcomes_family =
+      BuildCounter()
+          .Name("consensus_outcomes_total")
+          .Help("Number of consensus round outcomes by type")
+          .Register(*registry_);
+  // in the order of consensus::GateObject alternatives
+  static_assert(std::variant_size_v<consensus::GateObject> == 6);
+  std::array<Counter *, 6> consensus_outcomes{
+      &consensus_outcomes_family.Add({{"outcome", "commit"}}),
+      &consensus_outcomes_family.Add({{"outcome", "vote_other"}}),
+      &consensus_outcomes_family.Add({{"outcome", "proposal_reject"}}),
+      &consensus_outcomes_family.Add({{"outcome", "block_reject"}}),
+      &consensus_outcomes_family.Add({{"outcome", "agreement_on_none"}}),
+      &consensus_outcomes_family.Add({{"outcome", "future"}})};
+
+  round_switch_subscriber_ =
+      SubscriberCreator<bool, iroha::ordering::RoundSwitch>::template create<
+          EventTypes::kOnRoundSwitch>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, iroha::ordering::RoundSwitch const &) {
+            consensus_rounds.Increment();
+          });
+
+  votes_subscriber_ = SubscriberCreator<
+      bool,
+      std::vector<consensus::yac::VoteMessage>>::template create<EventTypes::
+                                                                     kOnState>(
+      SubscriptionEngineHandlers::kMetrics,
+      [&](auto &, std::vector<consensus::yac::VoteMessage> const &votes) {
+        consensus_votes.Increment(votes.size());
+      });
+
+  outcome_subscriber_ =
+      SubscriberCreator<bool, consensus::GateObject>::template create<
+          EventTypes::kOnOutcome>(
+          SubscriptionEngineHandlers::kMetrics,
+          [consensus_outcomes](auto &, consensus::GateObject const &object) {
+            consensus_outcomes[object.index()]->Increment();
           });
 
   auto &number_of_pending_mst_batches =
@@ -17,13 +17,17 @@
 
 #include "ametsuchi/storage.hpp"
 #include "ametsuchi/wsv_query.hpp"
+#include "consensus/gate_object.hpp"
+#include "consensus/yac/vote_message.hpp"
 #include "interfaces/common_objects/types.hpp"
 #include "interfaces/iroha_internal/block.hpp"
+#include "interfaces/iroha_internal/transaction_batch.hpp"
 #include "logger/logger_fwd.hpp"
 #include "main/rdb_status.hpp"
 #include "main/iroha_status.hpp"
 #include "main/subscription.hpp"
 #include "network/ordering_gate_common.hpp"
+#include "ordering/impl/round_switch.hpp"
 
 class Metrics : public std::enable_shared_from_this<Metrics> {
   using OnProposalSubscriber = iroha::BaseSubscriber<
@@ -34,6 +38,16 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   using MstMetrics = std::tuple<size_t, size_t>;
   using MstSubscriber = iroha::BaseSubscriber<bool, MstMetrics>;
   using RdbSubscriber = iroha::BaseSubscriber<bool, iroha::RocksDbStatus>;
+  using RoundSwitchSubscriber =
+      iroha::BaseSubscriber<bool, iroha::ordering::RoundSwitch>;
+  using VotesSubscriber = iroha::BaseSubscriber<
+      bool,
+      std::vector<iroha::consensus::yac::VoteMessage>>;
+  using OutcomeSubscriber =
+      iroha::BaseSubscriber<bool, iroha::consensus::GateObject>;
+  using DroppedBatchSubscriber = iroha::BaseSubscriber<
+      bool,
+      std::shared_ptr<shared_model::interface::TransactionBatch>>;
 
   std::string listen_addr_port_;
   std::shared_ptr<prometheus::Exposer> exposer_;
@@ -42,6 +56,10 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   std::shared_ptr<BlockSubscriber> block_subscriber_;
   std::shared_ptr<MstSubscriber> mst_subscriber_;
   std::shared_ptr<RdbSubscriber> rdb_subscriber_;
+  std::shared_ptr<RoundSwitchSubscriber> round_switch_subscriber_;
+  std::shared_ptr<VotesSubscriber> votes_subscriber_;
+  std::shared_ptr<OutcomeSubscriber> outcome_subscriber_;
+  std::shared_ptr<DroppedBatchSubscriber> dropped_batch_subscriber_;
   logger::LoggerPtr logger_;
   std::chrono::steady_clock::time_point uptime_start_timepoint_;
   std::thread uptime_thread_;
@@ -116,9 +116,11 @@ namespace iroha::ordering {
     assert(count(from.batches_) == from.tx_count_);
   }
 
-  BatchesCache::BatchesCache(std::chrono::minutes const &expiration_range)
+  BatchesCache::BatchesCache(std::optional<uint64_t> max_txs_count,
+                             std::chrono::minutes const &expiration_range)
       : mst_state_(
-            std::make_shared<utils::ReadWriteObject<MSTState, std::mutex>>()) {
+            std::make_shared<utils::ReadWriteObject<MSTState, std::mutex>>()),
+        max_txs_count_(max_txs_count) {
     getSubscription()->dispatcher()->repeat(
         SubscriptionEngineHandlers::kNotifications,
         std::chrono::seconds(10ull),  /// repeat task execution period
@@ -225,10 +227,34 @@ namespace iroha::ordering {
     });
   }
 
-  uint64_t BatchesCache::insert(
+  bool BatchesCache::exceedsLimit(
+      std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
+    if (not max_txs_count_)
+      return false;
+
+    auto const [is_pending, pending_txs_count] =
+        mst_state_->exclusiveAccess([&](auto &mst_state) {
+          return std::make_tuple(
+              mst_state.mst_pending_.count(batch->reducedHash()) != 0,
+              std::get<1>(mst_state.batches_and_txs_counter));
+        });
+    if (is_pending
+        or batches_cache_.getBatchesSet().count(batch) != 0
+        or used_batches_cache_.getBatchesSet().count(batch) != 0)
+      return false;
+
+    return batches_cache_.getTxsCount() + used_batches_cache_.getTxsCount()
+        + pending_txs_count + batch->transactions().size()
+        > *max_txs_count_;
+  }
+
+  std::optional<uint64_t> BatchesCache::insert(
       std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
     std::unique_lock lock(batches_cache_cs_);
 
+    if (exceedsLimit(batch))
+      return std::nullopt;
+
     if (batch->hasAllSignatures()) {
       if (used_batches_cache_.getBatchesSet().find(batch)
           == used_batches_cache_.getBatchesSet().end())
@@ -266,7 +292,12 @@ namespace iroha::ordering {
 
   uint64_t BatchesCache::txsCount() const {
     std::shared_lock lock(batches_cache_cs_);
-    return batches_cache_.getTxsCount() + used_batches_cache_.getTxsCount();
+    auto const pending_txs_count =
+        mst_state_->exclusiveAccess([](auto &mst_state) {
+          return std::get<1>(mst_state.batches_and_txs_counter);
+        });
+    return batches_cache_.getTxsCount() + used_batches_cache_.getTxsCount()
+        + pending_txs_count;
   }
 
   uint64_t BatchesCache::availableTxsCount() const {
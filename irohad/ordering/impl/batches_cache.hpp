@@ -11,6 +11,7 @@
 #include <map>
 #include <memory>
 #include <numeric>
+#include <optional>
 #include <set>
 #include <shared_mutex>
 #include <type_traits>
@@ -167,17 +168,45 @@ namespace iroha::ordering {
             &batch);
     void removeMSTCache(OnDemandOrderingService::HashesSetType const &hashes);
 
+    /**
+     * Check if a new batch does not fit into the transactions limit. Ready,
+     * used and MST pending transactions are counted. Batches which are
+     * already stored, e.g. pending batches with new signatures, always fit.
+     * Note: must be called under batches_cache_cs_ lock
+     */
+    bool exceedsLimit(
+        std::shared_ptr<shared_model::interface::TransactionBatch> const
+            &batch);
+
+    std::optional<uint64_t> const max_txs_count_;
+
    public:
     BatchesCache(BatchesCache const &) = delete;
     BatchesCache &operator=(BatchesCache const &) = delete;
-    BatchesCache(std::chrono::minutes const &expiration_range =
-                     std::chrono::minutes(24 * 60));
+    /**
+     * @param max_txs_count - maximum number of stored transactions, including
+     * MST pending ones. Unlimited if not set
+     * @param expiration_range - lifetime of MST pending batches
+     */
+    explicit BatchesCache(std::optional<uint64_t> max_txs_count = std::nullopt,
+                          std::chrono::minutes const &expiration_range =
+                              std::chrono::minutes(24 * 60));
 
-    uint64_t insert(
+    /**
+     * Insert batch to the cache or to the MST storage if the batch does not
+     * have all signatures yet
+     * @return number of transactions available for proposals or nullopt if
+     * the batch is dropped because the cache is full
+     */
+    std::optional<uint64_t> insert(
         std::shared_ptr<shared_model::interface::TransactionBatch> const
             &batch);
     void remove(const OnDemandOrderingService::HashesSetType &hashes);
     bool isEmpty();
+    /**
+     * @return number of stored transactions counted against the transactions
+     * limit: ready, used and MST pending ones
+     */
     uint64_t txsCount() const;
     uint64_t availableTxsCount() const;
 
@@ -69,9 +69,11 @@ OnDemandOrderingServiceImpl::OnDemandOrderingServiceImpl(
         proposal_factory,
     std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
     logger::LoggerPtr log,
-    size_t number_of_proposals)
+    size_t number_of_proposals,
+    std::optional<size_t> max_queue_size)
     : transaction_limit_(transaction_limit),
       number_of_proposals_(number_of_proposals),
+      batches_cache_(max_queue_size),
       proposal_factory_(std::move(proposal_factory)),
       tx_cache_(std::move(tx_cache)),
       log_(std::move(log)) {
@@ -148,10 +150,11 @@ void OnDemandOrderingServiceImpl::onCollaborationOutcome(
 }
 
 void OnDemandOrderingServiceImpl::onBatches(CollectionType batches) {
+  // batches which do not fit into the queue are dropped one by one, so that
+  // smaller batches of the collection still have a chance to be queued
   for (auto &batch : batches)
     if (not batchAlreadyProcessed(*batch))
-      if (!insertBatchToCache(batch))
-        break;
+      insertBatchToCache(batch);
 
   log_->info("onBatches => collection size = {}", batches.size());
 }
@@ -160,7 +163,14 @@ void OnDemandOrderingServiceImpl::onBatches(CollectionType batches) {
 bool OnDemandOrderingServiceImpl::insertBatchToCache(
     std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
   auto const available_txs_count = batches_cache_.insert(batch);
-  if (available_txs_count >= transaction_limit_)
+  if (not available_txs_count) {
+    log_->warn("Queue is full, batch {} is dropped", batch->reducedHash());
+    getSubscription()->notify(EventTypes::kOnDroppedBatches,
+                              std::shared_ptr(batch));
+    return false;
+  }
+
+  if (*available_txs_count >= transaction_limit_)
     getSubscription()->notify(EventTypes::kOnTxsEnoughForProposal,
                               std::shared_ptr(batch));
 
@@ -180,6 +190,10 @@ bool OnDemandOrderingServiceImpl::hasEnoughBatchesInCache() const {
   return batches_cache_.availableTxsCount() >= transaction_limit_;
 }
 
+uint64_t OnDemandOrderingServiceImpl::queueSize() const {
+  return batches_cache_.txsCount();
+}
+
 void OnDemandOrderingServiceImpl::forCachedBatches(
     std::function<void(BatchesSetType &)> const &f) {
   batches_cache_.forCachedBatches(f);
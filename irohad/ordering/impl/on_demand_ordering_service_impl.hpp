@@ -41,6 +41,9 @@ namespace iroha {
        * @param log to print progress
        * @param number_of_proposals - number of stored proposals, older will be
        * removed. Default value is 3
+       * @param max_queue_size - maximum number of transactions waiting in the
+       * service, new batches are dropped when it is reached. Unlimited if not
+       * set
        */
       OnDemandOrderingServiceImpl(
           size_t transaction_limit,
@@ -48,7 +51,8 @@ namespace iroha {
               proposal_factory,
           std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
           logger::LoggerPtr log,
-          size_t number_of_proposals = 3);
+          size_t number_of_proposals = 3,
+          std::optional<size_t> max_queue_size = std::nullopt);
 
       ~OnDemandOrderingServiceImpl() override;
 
@@ -103,6 +107,10 @@ namespace iroha {
       bool batchAlreadyProcessed(
           const shared_model::interface::TransactionBatch &batch);
 
+      /**
+       * Insert batch to the cache
+       * @return false if the batch is dropped because the queue is full
+       */
       bool insertBatchToCache(
           std::shared_ptr<shared_model::interface::TransactionBatch> const
               &batch);
@@ -114,6 +122,8 @@ namespace iroha {
 
       bool hasEnoughBatchesInCache() const override;
 
+      uint64_t queueSize() const override;
+
       void forCachedBatches(
           std::function<void(BatchesSetType &)> const &f) override;
 
@@ -113,6 +113,11 @@ namespace iroha {
 
       virtual bool hasEnoughBatchesInCache() const = 0;
 
+      /**
+       * @return number of transactions waiting in the service
+       */
+      virtual uint64_t queueSize() const = 0;
+
       virtual bool hasProposal(consensus::Round round) const = 0;
 
       virtual void processReceivedProposal(CollectionType batches) = 0;
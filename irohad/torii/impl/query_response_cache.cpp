@@ -0,0 +1,83 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "torii/query_response_cache.hpp"
+
+#include <mutex>
+
+#include "backend/protobuf/util.hpp"
+#include "cryptography/default_hash_provider.hpp"
+
+using iroha::torii::QueryResponseCache;
+
+QueryResponseCache::QueryResponseCache(size_t max_size)
+    : max_size_(max_size), version_(0) {}
+
+QueryResponseCache::Version QueryResponseCache::version() const {
+  std::shared_lock<std::shared_mutex> lock(mutex_);
+  return version_;
+}
+
+std::optional<iroha::protocol::QueryResponse> QueryResponseCache::find(
+    iroha::protocol::Query const &query) const {
+  auto key = makeKey(query);
+  if (not key) {
+    return std::nullopt;
+  }
+
+  std::shared_lock<std::shared_mutex> lock(mutex_);
+  if (auto it = responses_.find(*key); it != responses_.end()) {
+    return it->second;
+  }
+  return std::nullopt;
+}
+
+void QueryResponseCache::insert(iroha::protocol::Query const &query,
+                                iroha::protocol::QueryResponse const &response,
+                                Version version) {
+  if (response.has_error_response()) {
+    return;
+  }
+
+  auto key = makeKey(query);
+  if (not key) {
+    return;
+  }
+
+  std::unique_lock<std::shared_mutex> lock(mutex_);
+  if (version != version_ or responses_.size() >= max_size_) {
+    return;
+  }
+  responses_.emplace(std::move(*key), response);
+}
+
+void QueryResponseCache::invalidate() {
+  std::unique_lock<std::shared_mutex> lock(mutex_);
+  ++version_;
+  responses_.clear();
+}
+
+size_t QueryResponseCache::size() const {
+  std::shared_lock<std::shared_mutex> lock(mutex_);
+  return responses_.size();
+}
+
+std::optional<shared_model::crypto::Hash> QueryResponseCache::makeKey(
+    iroha::protocol::Query const &query) {
+  // pending transactions are not a part of the committed ledger state
+  if (query.payload().has_get_pending_transactions()) {
+    return std::nullopt;
+  }
+
+  iroha::protocol::Query key_query;
+  *key_query.mutable_payload() = query.payload();
+  key_query.mutable_payload()->mutable_meta()->clear_created_time();
+  key_query.mutable_payload()->mutable_meta()->clear_query_counter();
+  key_query.mutable_signature()->set_public_key(
+      query.signature().public_key());
+
+  return shared_model::crypto::DefaultHashProvider::makeHash(
+      shared_model::proto::makeBlob(key_query));
+}
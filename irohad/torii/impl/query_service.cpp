@@ -25,10 +25,12 @@ QueryService::QueryService(
     logger::LoggerPtr log,
     std::shared_ptr<iroha::BaseSubscriber<
         iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
-        iroha::IrohaStatus>> iroha_status_subscription)
+        iroha::IrohaStatus>> iroha_status_subscription,
+    std::shared_ptr<QueryResponseCache> response_cache)
     : query_processor_{std::move(query_processor)},
       query_factory_{std::move(query_factory)},
       blocks_query_factory_{std::move(blocks_query_factory)},
+      response_cache_{std::move(response_cache)},
       log_{std::move(log)},
       iroha_status_subscription_(std::move(iroha_status_subscription)) {}
 
@@ -46,13 +48,26 @@ void QueryService::Find(iroha::protocol::Query const &request,
   }
 
   query_factory_->build(request).match(
-      [this, &hash, &response](const auto &query) {
+      [this, &request, &hash, &response](const auto &query) {
+        if (response_cache_) {
+          if (auto cached = response_cache_->find(request)) {
+            response = std::move(*cached);
+            response.set_query_hash(hash.hex());
+            cache_.addItem(hash, 0);
+            return;
+          }
+        }
+        auto const cache_version =
+            response_cache_ ? response_cache_->version() : 0;
         query_processor_->queryHandle(*query.value) |
             [&](auto &&iface_response) {
               // Send query to iroha
               response = static_cast<shared_model::proto::QueryResponse &>(
                              *iface_response)
                              .getTransport();
+              if (response_cache_) {
+                response_cache_->insert(request, response, cache_version);
+              }
               // TODO 18.02.2019 lebdron: IR-336 Replace cache
               // 0 is used as a dummy value
               cache_.addItem(hash, 0);
@@ -0,0 +1,95 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef TORII_QUERY_RESPONSE_CACHE_HPP
+#define TORII_QUERY_RESPONSE_CACHE_HPP
+
+#include <cstdint>
+#include <optional>
+#include <shared_mutex>
+#include <unordered_map>
+
+#include "cryptography/hash.hpp"
+#include "qry_responses.pb.h"
+#include "queries.pb.h"
+
+namespace iroha::torii {
+  /**
+   * Cache of responses to queries which depend only on the committed ledger
+   * state. Intended to absorb the load of clients polling the same query
+   * (e.g. account balances) between blocks.
+   *
+   * Entries are keyed by the query payload without its replay protection
+   * fields (created time and query counter) together with the signer public
+   * key, so that a cached response is only served to the same creator signing
+   * with the same key that has already passed stateful validation. The whole
+   * cache is invalidated on every block commit.
+   */
+  class QueryResponseCache {
+   public:
+    /// Monotonic counter of cache invalidations
+    using Version = uint64_t;
+
+    /**
+     * @param max_size - maximum number of stored responses; new responses are
+     * not stored while the cache is full
+     */
+    explicit QueryResponseCache(size_t max_size);
+
+    /**
+     * @return current version of the cache, which must be passed to insert
+     * for a response computed after this call
+     */
+    Version version() const;
+
+    /**
+     * Look up a response to an equivalent query
+     * @param query - query to look up
+     * @return cached response if present, its query hash is not updated
+     */
+    std::optional<iroha::protocol::QueryResponse> find(
+        iroha::protocol::Query const &query) const;
+
+    /**
+     * Store a response to the query. Error responses, responses to queries
+     * which do not depend on the ledger state only and responses computed
+     * before the last invalidation are ignored.
+     * @param query - processed query
+     * @param response - response to the query
+     * @param version - cache version obtained before processing the query
+     */
+    void insert(iroha::protocol::Query const &query,
+                iroha::protocol::QueryResponse const &response,
+                Version version);
+
+    /**
+     * Drop all stored responses. Must be called on each block commit.
+     */
+    void invalidate();
+
+    /**
+     * @return number of stored responses
+     */
+    size_t size() const;
+
+   private:
+    /**
+     * @return cache key for the query, or nullopt if the query is not
+     * cacheable
+     */
+    static std::optional<shared_model::crypto::Hash> makeKey(
+        iroha::protocol::Query const &query);
+
+    size_t const max_size_;
+    mutable std::shared_mutex mutex_;
+    Version version_;
+    std::unordered_map<shared_model::crypto::Hash,
+                       iroha::protocol::QueryResponse,
+                       shared_model::crypto::Hash::Hasher>
+        responses_;
+  };
+}  // namespace iroha::torii
+
+#endif  // TORII_QUERY_RESPONSE_CACHE_HPP
@@ -19,6 +19,7 @@
 #include "main/iroha_status.hpp"
 #include "main/subscription.hpp"
 #include "torii/processor/query_processor.hpp"
+#include "torii/query_response_cache.hpp"
 
 namespace shared_model::interface {
   template <typename Interface, typename Transport>
@@ -48,7 +49,8 @@ namespace iroha::torii {
         logger::LoggerPtr log,
         std::shared_ptr<iroha::BaseSubscriber<
             iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
-            iroha::IrohaStatus>> iroha_status_subscription);
+            iroha::IrohaStatus>> iroha_status_subscription,
+        std::shared_ptr<QueryResponseCache> response_cache = nullptr);
 
     QueryService(const QueryService &) = delete;
     QueryService &operator=(const QueryService &) = delete;
@@ -87,6 +89,9 @@ namespace iroha::torii {
                         shared_model::crypto::Hash::Hasher>
         cache_;
 
+    /// optional cache of responses to repeated ledger state queries
+    std::shared_ptr<QueryResponseCache> response_cache_;
+
     logger::LoggerPtr log_;
     std::shared_ptr<iroha::BaseSubscriber<
         iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
@@ -73,6 +73,10 @@ namespace integration_framework::fake_peer {
     return false;
   }
 
+  uint64_t OnDemandOsNetworkNotifier::queueSize() const {
+    return 0;
+  }
+
   void OnDemandOsNetworkNotifier::processReceivedProposal(
       CollectionType batches) {}
 
@@ -45,6 +45,8 @@ namespace integration_framework::fake_peer {
 
     bool hasEnoughBatchesInCache() const override;
 
+    uint64_t queueSize() const override;
+
     bool hasProposal(iroha::consensus::Round round) const override;
 
     void processReceivedProposal(CollectionType batches) override;
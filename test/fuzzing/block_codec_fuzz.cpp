@@ -0,0 +1,21 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "fuzzing/codec_round_trip.hpp"
+
+#include "backend/protobuf/block.hpp"
+#include "validators/protobuf/proto_block_validator.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  static shared_model::validation::ProtoBlockValidator validator;
+
+  iroha::protocol::Block_v1 block;
+  if (protobuf_mutator::libfuzzer::LoadProtoInput(true, data, size, &block)) {
+    fuzzing::checkCodecRoundTrip<shared_model::proto::Block>(std::move(block),
+                                                             validator);
+  }
+
+  return 0;
+}
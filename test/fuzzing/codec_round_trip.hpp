@@ -0,0 +1,73 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_FUZZING_CODEC_ROUND_TRIP_HPP
+#define IROHA_FUZZING_CODEC_ROUND_TRIP_HPP
+
+#include <cstdlib>
+#include <string>
+
+#include <google/protobuf/util/json_util.h>
+#include <libfuzzer/libfuzzer_macro.h>
+#include "common/result.hpp"
+#include "converters/protobuf/json_proto_converter.hpp"
+
+namespace fuzzing {
+
+  /**
+   * Checks that a stateless valid model survives binary and JSON round trips
+   * without changing its serialization and hash, i.e. the signed payload.
+   * Aborts on mismatch, so that the fuzzer reports the input.
+   * @tparam Model - shared model proto class
+   * @param transport - fuzzed transport object of the model
+   * @param validator - proto validator of the transport object
+   */
+  template <typename Model, typename Validator>
+  void checkCodecRoundTrip(typename Model::TransportType transport,
+                           Validator const &validator) {
+    using shared_model::converters::protobuf::jsonToProto;
+
+    if (validator.validate(transport)) {
+      return;
+    }
+    // unknown fields are preserved by the binary format only, so they would
+    // change the hash after the JSON round trip
+    transport.DiscardUnknownFields();
+
+    Model model(std::move(transport));
+    auto const serialized = model.getTransport().SerializeAsString();
+
+    typename Model::TransportType parsed;
+    if (not parsed.ParseFromString(serialized)) {
+      std::abort();
+    }
+    Model reparsed(std::move(parsed));
+    if (reparsed.getTransport().SerializeAsString() != serialized
+        or not(reparsed.hash() == model.hash())) {
+      std::abort();
+    }
+
+    // models which cannot be represented in JSON, e.g. with invalid UTF-8
+    // in string fields, are not a round trip failure
+    std::string json;
+    if (not google::protobuf::util::MessageToJsonString(model.getTransport(),
+                                                        &json)
+                .ok()) {
+      return;
+    }
+    auto json_result = jsonToProto<typename Model::TransportType>(json);
+    if (iroha::expected::hasError(json_result)) {
+      std::abort();
+    }
+    Model restored(std::move(json_result).assumeValue());
+    if (restored.getTransport().SerializeAsString() != serialized
+        or not(restored.hash() == model.hash())) {
+      std::abort();
+    }
+  }
+
+}  // namespace fuzzing
+
+#endif  // IROHA_FUZZING_CODEC_ROUND_TRIP_HPP
@@ -0,0 +1,21 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "fuzzing/codec_round_trip.hpp"
+
+#include "backend/protobuf/queries/proto_query.hpp"
+#include "validators/protobuf/proto_query_validator.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  static shared_model::validation::ProtoQueryValidator validator;
+
+  iroha::protocol::Query query;
+  if (protobuf_mutator::libfuzzer::LoadProtoInput(true, data, size, &query)) {
+    fuzzing::checkCodecRoundTrip<shared_model::proto::Query>(std::move(query),
+                                                             validator);
+  }
+
+  return 0;
+}
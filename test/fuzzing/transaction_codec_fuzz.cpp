@@ -0,0 +1,21 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "fuzzing/codec_round_trip.hpp"
+
+#include "backend/protobuf/transaction.hpp"
+#include "validators/protobuf/proto_transaction_validator.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  static shared_model::validation::ProtoTransactionValidator validator;
+
+  iroha::protocol::Transaction tx;
+  if (protobuf_mutator::libfuzzer::LoadProtoInput(true, data, size, &tx)) {
+    fuzzing::checkCodecRoundTrip<shared_model::proto::Transaction>(
+        std::move(tx), validator);
+  }
+
+  return 0;
+}
@@ -6,6 +6,7 @@
 #include "integration/executor/executor_fixture.hpp"
 
 #include <gtest/gtest.h>
+#include "ametsuchi/setting_query.hpp"
 #include "backend/plain/account_detail_record_id.hpp"
 #include "common/result.hpp"
 #include "framework/common_constants.hpp"
@@ -165,6 +166,79 @@ TEST_P(SetAccountDetailBasicTest, RootPermission) {
   checkDetails(kUserId, DetailsByKeyByWriter{{{kUserId, {{kKey, kVal}}}}});
 }
 
+/**
+ * @given a user with a detail and MaxAccountDetailsSize setting equal to the
+ * size of this detail
+ * @when the user overwrites the detail with a value of the same size and then
+ * sets another detail
+ * @then the first command succeeds and the second one fails with error code 9
+ */
+TEST_P(SetAccountDetailBasicTest, DetailsSizeLimit) {
+  getItf().createUserWithPerms(
+      kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {});
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructSetSettingValue(
+          iroha::ametsuchi::kMaxAccountDetailsSizeKey,
+          std::to_string(kKey.size() + kVal.size()))));
+  IROHA_ASSERT_RESULT_VALUE(setDetail(kUserId, kKey, kVal, kUserId));
+
+  const AccountDetailValueType new_val{"VALUE"};
+  IROHA_ASSERT_RESULT_VALUE(setDetail(kUserId, kKey, new_val, kUserId));
+  checkCommandError(setDetail(kUserId, "key2", kVal, kUserId), 9);
+  checkDetails(kUserId, DetailsByKeyByWriter{{{kUserId, {{kKey, new_val}}}}});
+}
+
+/**
+ * @given a user with a detail and MaxAccountDetailsSize setting equal to the
+ * size of this detail
+ * @when the user compares and sets the detail to a value of the same size and
+ * then compares and sets another detail
+ * @then the first command succeeds and the second one fails with error code 9
+ */
+TEST_P(SetAccountDetailBasicTest, CompareAndSetDetailsSizeLimit) {
+  getItf().createUserWithPerms(kUser,
+                               kDomain,
+                               PublicKeyHexStringView{kUserKeypair.publicKey()},
+                               {Role::kGetMyAccDetail});
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructSetSettingValue(
+          iroha::ametsuchi::kMaxAccountDetailsSizeKey,
+          std::to_string(kKey.size() + kVal.size()))));
+  IROHA_ASSERT_RESULT_VALUE(setDetail(kUserId, kKey, kVal, kUserId));
+
+  auto compare_and_set = [&](const AccountDetailKeyType &key,
+                             const AccountDetailValueType &value,
+                             std::optional<AccountDetailValueType> old_value) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructCompareAndSetAccountDetail(
+            kUserId, key, value, old_value, false),
+        kUserId,
+        true);
+  };
+  const AccountDetailValueType new_val{"VALUE"};
+  IROHA_ASSERT_RESULT_VALUE(compare_and_set(kKey, new_val, kVal));
+  checkCommandError(compare_and_set("key2", kVal, std::nullopt), 9);
+  checkDetails(kUserId, DetailsByKeyByWriter{{{kUserId, {{kKey, new_val}}}}});
+}
+
+/**
+ * @given a user and MaxAccountDetailsSize setting which is not a number
+ * representable as a signed 64 bit integer
+ * @when the user sets a detail
+ * @then the setting is ignored and the command succeeds
+ */
+TEST_P(SetAccountDetailBasicTest, InvalidDetailsSizeLimitIsIgnored) {
+  getItf().createUserWithPerms(
+      kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {});
+  for (auto const &setting : {"10 bytes", "9223372036854775808"}) {
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::kMaxAccountDetailsSizeKey, setting)));
+    IROHA_ASSERT_RESULT_VALUE(setDetail(kUserId, kKey, kVal, kUserId));
+  }
+  checkDetails(kUserId, DetailsByKeyByWriter{{{kUserId, {{kKey, kVal}}}}});
+}
+
 INSTANTIATE_TEST_SUITE_P(Base,
                          SetAccountDetailBasicTest,
                          executor_testing::getExecutorTestParams(),
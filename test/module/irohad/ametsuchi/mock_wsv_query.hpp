@@ -54,6 +54,12 @@ namespace iroha {
                    iroha::expected::Result<size_t, std::string>(bool));
       MOCK_METHOD0(countDomains,
                    iroha::expected::Result<size_t, std::string>());
+      MOCK_METHOD0(countAccounts,
+                   iroha::expected::Result<size_t, std::string>());
+      MOCK_METHOD0(countAssets,
+                   iroha::expected::Result<size_t, std::string>());
+      MOCK_METHOD0(countRoles,
+                   iroha::expected::Result<size_t, std::string>());
       MOCK_METHOD0(countTransactions,
                    iroha::expected::Result<size_t, std::string>());
     };
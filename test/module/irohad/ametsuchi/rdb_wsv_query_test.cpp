@@ -33,7 +33,7 @@ namespace iroha {
         auto db_port = std::make_shared<RocksDBPort>();
         db_port->initialize(db_name_);
 
-        auto db_context = std::make_shared<RocksDBContext>(db_port);
+        db_context = std::make_shared<RocksDBContext>(db_port);
         command = std::make_unique<RocksDBWsvCommand>(db_context);
         query = std::make_unique<RocksDBWsvQuery>(db_context,
                                                   getTestLogger("WsvQuery"));
@@ -42,12 +42,14 @@ namespace iroha {
       void TearDown() override {
         command.reset();
         query.reset();
+        db_context.reset();
 
         fs::remove_all(db_name_);
         AmetsuchiTest::TearDown();
       }
 
       std::string db_name_;
+      std::shared_ptr<RocksDBContext> db_context;
       std::unique_ptr<WsvCommand> command;
       std::unique_ptr<WsvQuery> query;
     };
@@ -188,5 +190,71 @@ namespace iroha {
                   testing::UnorderedElementsAre(pub_key1, pub_key2));
     }
 
+    /**
+     * @given storage with accounts, asset definitions and roles but without
+     * the accounts and asset definitions total counts, as created by the
+     * previous versions
+     * @when the total counts are restored
+     * AND the counts are queried
+     * @then the counts of enumerated accounts and asset definitions are
+     * returned
+     */
+    TEST_F(RdbWsvQueryTest, RestoreTotalCounts) {
+      command->insertRole("role");
+      command->insertRole("another_role");
+      shared_model::plain::Domain domain("domain", "role");
+      command->insertDomain(domain);
+      command->insertAccount(
+          shared_model::plain::Account("account", "domain", 1, "{}"));
+      command->insertAccount(
+          shared_model::plain::Account("another", "domain", 1, "{}"));
+      command->insertAccountSignatory("account@domain",
+                                      PublicKeyHexStringView{"0a"sv});
+
+      {
+        RocksDbCommon common(db_context);
+        common.encode(1);
+        ASSERT_FALSE(expected::hasError(
+            forAsset<kDbOperation::kPut>(common, "coin", "domain")));
+        common.valueBuffer().assign("1.0");
+        ASSERT_FALSE(expected::hasError(forAccountAsset<kDbOperation::kPut>(
+            common, "account", "domain", "coin#domain")));
+      }
+      ASSERT_EQ(query->countAccounts().assumeValue(), 0);
+      ASSERT_EQ(query->countAssets().assumeValue(), 0);
+
+      {
+        RocksDbCommon common(db_context);
+        ASSERT_FALSE(expected::hasError(restoreTotalCounts(common)));
+      }
+
+      ASSERT_EQ(query->countAccounts().assumeValue(), 2);
+      ASSERT_EQ(query->countAssets().assumeValue(), 1);
+      ASSERT_EQ(query->countRoles().assumeValue(), 2);
+    }
+
+    /**
+     * @given storage with the accounts and asset definitions total counts
+     * @when the total counts are restored
+     * AND the counts are queried
+     * @then the stored counts are returned
+     */
+    TEST_F(RdbWsvQueryTest, CountWithTotalCounts) {
+      {
+        RocksDbCommon common(db_context);
+        common.encode(5ull);
+        ASSERT_FALSE(expected::hasError(
+            forAccountsTotalCount<kDbOperation::kPut>(common)));
+        common.encode(3ull);
+        ASSERT_FALSE(expected::hasError(
+            forAssetsTotalCount<kDbOperation::kPut>(common)));
+        ASSERT_FALSE(expected::hasError(restoreTotalCounts(common)));
+      }
+
+      ASSERT_EQ(query->countAccounts().assumeValue(), 5);
+      ASSERT_EQ(query->countAssets().assumeValue(), 3);
+      ASSERT_EQ(query->countRoles().assumeValue(), 0);
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
@@ -862,3 +862,34 @@ TEST_F(RocksDBTest, Signatories) {
   ASSERT_TRUE(status.ok());
   ASSERT_EQ(counter, 3);
 }
+
+/**
+ * @given account details without the stored details size
+ * @when the details size is requested
+ * @then the sizes of keys and values of the details are summed up
+ * AND the stored details size is returned once it is set
+ */
+TEST_F(RocksDBTest, AccountDetailsSize) {
+  RocksDbCommon common(tx_context_);
+  common.valueBuffer() = "value";
+  ASSERT_FALSE(iroha::expected::hasError(forAccountDetail<kDbOperation::kPut>(
+      common, "acc", "dom", "writer@dom", "key")));
+  common.valueBuffer() = "another_value";
+  ASSERT_FALSE(iroha::expected::hasError(forAccountDetail<kDbOperation::kPut>(
+      common, "acc", "dom", "another@dom", "key2")));
+
+  {
+    auto result = accountDetailsSize(common, "acc", "dom");
+    ASSERT_FALSE(iroha::expected::hasError(result));
+    ASSERT_EQ(result.assumeValue(), 25);
+  }
+
+  common.encode(42ull);
+  ASSERT_FALSE(iroha::expected::hasError(
+      forAccountDetailsSize<kDbOperation::kPut>(common, "acc", "dom")));
+  {
+    auto result = accountDetailsSize(common, "acc", "dom");
+    ASSERT_FALSE(iroha::expected::hasError(result));
+    ASSERT_EQ(result.assumeValue(), 42);
+  }
+}
@@ -103,6 +103,23 @@ namespace iroha {
       ASSERT_EQ(query->countDomains().assumeValue(), 2);
     }
 
+    TEST_F(WsvQueryTest, countAccountsAndRoles) {
+      using shared_model::plain::Account;
+      using shared_model::plain::Domain;
+      using namespace iroha::expected;
+      ASSERT_EQ(query->countRoles().assumeValue(), 0);
+      ASSERT_EQ(query->countAccounts().assumeValue(), 0);
+      command->insertRole("user");
+      command->insertRole("admin");
+      ASSERT_FALSE(hasError(command->insertDomain(Domain{"aaa", "user"})));
+      ASSERT_FALSE(
+          hasError(command->insertAccount(Account{"a@aaa", "aaa", 1, "{}"})));
+      ASSERT_FALSE(
+          hasError(command->insertAccount(Account{"b@aaa", "aaa", 1, "{}"})));
+      ASSERT_EQ(query->countRoles().assumeValue(), 2);
+      ASSERT_EQ(query->countAccounts().assumeValue(), 2);
+    }
+
     TEST_F(WsvQueryTest, countPeers) {
       ASSERT_EQ(query->countPeers(false).assumeValue(), 0);
       command->insertPeer(
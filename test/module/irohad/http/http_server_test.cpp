@@ -0,0 +1,133 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "http/http_server.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <boost/asio.hpp>
+
+#include "framework/integration_framework/port_guard.hpp"
+#include "framework/test_logger.hpp"
+
+using namespace iroha::network;
+using ::testing::HasSubstr;
+using ::testing::StartsWith;
+
+class HttpServerTest : public ::testing::Test {
+ public:
+  static constexpr uint16_t kMinPort = 20000;
+
+  void SetUp() override {
+    port_ = port_guard_.getPort(kMinPort);
+    port_guard_.unbind(port_);
+  }
+
+  void startServer(HttpServer::Options options = {}) {
+    options.ports = std::to_string(port_);
+    server_ = std::make_unique<HttpServer>(std::move(options),
+                                           getTestLogger("HttpServer"));
+    ASSERT_TRUE(server_->start());
+    server_->registerHandler("/ok", [](HttpRequestResponse &req_res) {
+      req_res.setJsonResponse(R"({"ok":true})");
+    });
+    server_->registerHandler("/unavailable", [](HttpRequestResponse &req_res) {
+      req_res.setJsonResponse(R"({"ok":false})", 503);
+    });
+  }
+
+  /**
+   * Send GET request to the server
+   * @param uri of the request
+   * @return raw response with the status line and headers
+   */
+  std::string get(std::string_view uri) {
+    using namespace boost::asio;
+    io_context ioctx;
+    ip::tcp::socket socket(ioctx);
+    socket.connect({ip::make_address_v4("127.0.0.1"), port_});
+    auto const request =
+        "GET " + std::string(uri) + " HTTP/1.0\r\nHost: 127.0.0.1\r\n\r\n";
+    write(socket, buffer(request));
+
+    std::string response;
+    boost::system::error_code ec;
+    read(socket, dynamic_buffer(response), ec);
+    return response;
+  }
+
+ protected:
+  integration_framework::PortGuard port_guard_;
+  uint16_t port_;
+  std::unique_ptr<HttpServer> server_;
+};
+
+/**
+ * @given started http server with a handler responding with JSON
+ * @when the handler is requested
+ * @then the response has status 200, JSON content type and length, and the
+ * handler data
+ */
+TEST_F(HttpServerTest, JsonResponse) {
+  ASSERT_NO_FATAL_FAILURE(startServer());
+
+  auto const response = get("/ok");
+  EXPECT_THAT(response, StartsWith("HTTP/1.1 200"));
+  EXPECT_THAT(response,
+              HasSubstr("Content-Type: application/json; charset=utf-8\r\n"));
+  EXPECT_THAT(response, HasSubstr("Content-Length: 11\r\n"));
+  EXPECT_THAT(response, HasSubstr("\r\n\r\n{\"ok\":true}"));
+}
+
+/**
+ * @given started http server with a handler responding with an error status
+ * @when the handler is requested
+ * @then the response has the error status, JSON content type and length, and
+ * the handler data
+ */
+TEST_F(HttpServerTest, JsonResponseWithErrorStatus) {
+  ASSERT_NO_FATAL_FAILURE(startServer());
+
+  auto const response = get("/unavailable");
+  EXPECT_THAT(response, StartsWith("HTTP/1.1 503"));
+  EXPECT_THAT(response,
+              HasSubstr("Content-Type: application/json; charset=utf-8\r\n"));
+  EXPECT_THAT(response, HasSubstr("Content-Length: 12\r\n"));
+  EXPECT_THAT(response, HasSubstr("\r\n\r\n{\"ok\":false}"));
+}
+
+/**
+ * @given started http server with access_control_allow_origin option
+ * @when handlers responding with success and error statuses are requested
+ * @then both responses have a single Access-Control-Allow-Origin header with
+ * the configured value
+ */
+TEST_F(HttpServerTest, AllowOriginHeader) {
+  HttpServer::Options options;
+  options.access_control_allow_origin = "https://example.org";
+  ASSERT_NO_FATAL_FAILURE(startServer(std::move(options)));
+
+  for (auto uri : {"/ok", "/unavailable"}) {
+    auto const response = get(uri);
+    static std::string const kHeader{"Access-Control-Allow-Origin:"};
+    auto const pos = response.find(kHeader);
+    ASSERT_NE(pos, std::string::npos) << response;
+    EXPECT_THAT(response.substr(pos),
+                StartsWith(kHeader + " https://example.org\r\n"));
+    EXPECT_EQ(response.find(kHeader, pos + 1), std::string::npos) << response;
+  }
+}
+
+/**
+ * @given started http server without access_control_allow_origin option
+ * @when a handler is requested
+ * @then the response has no Access-Control-Allow-Origin header
+ */
+TEST_F(HttpServerTest, NoAllowOriginHeaderByDefault) {
+  ASSERT_NO_FATAL_FAILURE(startServer());
+
+  EXPECT_THAT(get("/ok"),
+              ::testing::Not(HasSubstr("Access-Control-Allow-Origin")));
+}
@@ -0,0 +1,63 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/iroha_status.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha;
+
+class IrohaStatusTest : public ::testing::Test {
+ public:
+  IrohaStatus status{std::nullopt,
+                     consensus::Round{1, 0},
+                     false,
+                     true,
+                     std::nullopt};
+};
+
+/**
+ * @given status of a healthy node which takes part in consensus and is not
+ * synchronizing
+ * @when readiness is checked
+ * @then the node is ready
+ */
+TEST_F(IrohaStatusTest, Ready) {
+  EXPECT_TRUE(isReady(status));
+}
+
+/**
+ * @given status of a node which has not taken part in consensus rounds yet
+ * @when readiness is checked
+ * @then the node is not ready
+ */
+TEST_F(IrohaStatusTest, NoRoundIsNotReady) {
+  status.last_round.reset();
+  EXPECT_FALSE(isReady(status));
+}
+
+/**
+ * @given status of a synchronizing node
+ * @when readiness is checked
+ * @then the node is not ready
+ */
+TEST_F(IrohaStatusTest, SyncingIsNotReady) {
+  status.is_syncing = true;
+  EXPECT_FALSE(isReady(status));
+  status.is_syncing.reset();
+  EXPECT_FALSE(isReady(status));
+}
+
+/**
+ * @given status of an unhealthy node
+ * @when readiness is checked
+ * @then the node is not ready
+ */
+TEST_F(IrohaStatusTest, UnhealthyIsNotReady) {
+  status.is_healthy = false;
+  EXPECT_FALSE(isReady(status));
+  status.is_healthy.reset();
+  EXPECT_FALSE(isReady(status));
+}
@@ -182,3 +182,35 @@ TEST_F(MSTProcessingTest, DoubleTxs) {
       addSignaturesFromKeyPairs(get_batch_2(), 0, makeKey()));
   ASSERT_EQ(batches_cache_->availableTxsCount(), 2);
 }
+
+/**
+ * @given batches cache with a limit of 2 transactions
+ * @when a batch collecting signatures and a ready batch are inserted
+ * AND another new batch is inserted
+ * @then the new batch is dropped since pending transactions are counted too
+ * AND the pending batch can still be completed with the missing signature
+ * AND the stored transactions count matches the limited quantity
+ */
+TEST_F(MSTProcessingTest, LimitCountsPendingBatches) {
+  batches_cache_ = std::make_shared<iroha::ordering::BatchesCache>(2);
+  auto first_signature = makeSignature("1"_hex_sig, "pub_key_1"_hex_pubkey);
+  auto second_signature = makeSignature("2"_hex_sig, "pub_key_2"_hex_pubkey);
+  auto pending_tx = makeTestBatch(txBuilder(1, iroha::time::now(), 2));
+
+  ASSERT_TRUE(
+      batches_cache_->insert(addSignatures(pending_tx, 0, first_signature)));
+  ASSERT_TRUE(batches_cache_->insert(addSignaturesFromKeyPairs(
+      makeTestBatch(txBuilder(2, iroha::time::now(), 1)), 0, makeKey())));
+  ASSERT_EQ(batches_cache_->availableTxsCount(), 1);
+  ASSERT_EQ(batches_cache_->txsCount(), 2);
+
+  EXPECT_FALSE(batches_cache_->insert(addSignaturesFromKeyPairs(
+      makeTestBatch(txBuilder(3, iroha::time::now(), 1)), 0, makeKey())));
+  EXPECT_FALSE(batches_cache_->insert(addSignatures(
+      makeTestBatch(txBuilder(4, iroha::time::now(), 2)), 0, first_signature)));
+
+  EXPECT_TRUE(
+      batches_cache_->insert(addSignatures(pending_tx, 0, second_signature)));
+  EXPECT_EQ(batches_cache_->availableTxsCount(), 2);
+  EXPECT_EQ(batches_cache_->txsCount(), 2);
+}
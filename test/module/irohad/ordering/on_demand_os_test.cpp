@@ -104,6 +104,28 @@ class OnDemandOsTest : public ::testing::Test {
     return collection;
   }
 
+  /**
+   * Replace the OS with one which has a queue size limit
+   * @param max_queue_size - maximum number of queued transactions
+   */
+  void makeOsWithQueueSizeLimit(size_t max_queue_size) {
+    auto tx_cache =
+        std::make_unique<NiceMock<iroha::ametsuchi::MockTxPresenceCache>>();
+    ON_CALL(*tx_cache,
+            check(A<const shared_model::interface::TransactionBatch &>()))
+        .WillByDefault(Return(std::vector<iroha::ametsuchi::TxCacheStatusType>{
+            iroha::ametsuchi::tx_cache_status_responses::Missing()}));
+    os = std::make_shared<OnDemandOrderingServiceImpl>(
+        transaction_limit,
+        std::make_unique<
+            shared_model::proto::ProtoProposalFactory<MockProposalValidator>>(
+            iroha::test::kTestsValidatorsConfig),
+        std::move(tx_cache),
+        getTestLogger("OdOrderingService"),
+        proposal_limit,
+        max_queue_size);
+  }
+
   std::unique_ptr<Proposal> makeMockProposal() {
     auto proposal = std::make_unique<NiceMock<MockProposal>>();
     // TODO: nickaleks IR-1811 clone should return initialized mock
@@ -158,6 +180,56 @@ TEST_F(OnDemandOsTest, OverflowRound) {
             os->onRequestProposal(target_round)->first->transactions().size());
 }
 
+/**
+ * @given on-demand OS with a queue size limit less than proposal size
+ * @when  send number of transactions greater that the queue size limit
+ * AND initiate next round
+ * @then  check that the proposal has only the queue size limit of transactions
+ */
+TEST_F(OnDemandOsTest, QueueSizeLimit) {
+  const size_t max_queue_size = transaction_limit / 2;
+  makeOsWithQueueSizeLimit(max_queue_size);
+
+  generateTransactionsAndInsert({1, transaction_limit});
+
+  os->onCollaborationOutcome(commit_round);
+
+  ASSERT_TRUE(os->onRequestProposal(target_round));
+  ASSERT_EQ(max_queue_size,
+            os->onRequestProposal(target_round)->first->transactions().size());
+}
+
+/**
+ * @given on-demand OS with a queue size limit
+ * @when  send a collection where a batch bigger than the remaining queue
+ * space is followed by smaller batches
+ * AND initiate next round
+ * @then  check that only the big batch is dropped
+ */
+TEST_F(OnDemandOsTest, QueueSizeLimitDropsOnlyOversizedBatch) {
+  makeOsWithQueueSizeLimit(2);
+
+  auto big_batch_txs = generateTransactions({1, 4});
+  shared_model::interface::types::SharedTxsCollectionType txs;
+  for (auto &batch : big_batch_txs) {
+    txs.push_back(batch->transactions().front());
+  }
+  OnDemandOrderingService::CollectionType collection;
+  collection.push_back(
+      std::make_shared<shared_model::interface::TransactionBatchImpl>(
+          std::move(txs)));
+  for (auto &batch : generateTransactions({4, 6})) {
+    collection.push_back(std::move(batch));
+  }
+  os->onBatches(std::move(collection));
+
+  os->onCollaborationOutcome(commit_round);
+
+  ASSERT_TRUE(os->onRequestProposal(target_round));
+  ASSERT_EQ(2,
+            os->onRequestProposal(target_round)->first->transactions().size());
+}
+
 /**
  * @given initialized on-demand OS
  * @when  insert commit round and then proposal_limit + 2 reject rounds
@@ -41,6 +41,7 @@ namespace iroha::ordering {
                           OnDemandOrderingService::BatchesSetType &)> const &));
     MOCK_METHOD(bool, isEmptyBatchesCache, (), (override));
     MOCK_METHOD(bool, hasEnoughBatchesInCache, (), (const, override));
+    MOCK_METHOD(uint64_t, queueSize, (), (const, override));
     MOCK_METHOD(bool, hasProposal, (consensus::Round), (const, override));
     MOCK_METHOD(void, processReceivedProposal, (CollectionType), (override));
 
@@ -0,0 +1,117 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "torii/query_response_cache.hpp"
+
+#include <gtest/gtest.h>
+
+#include "builders/protobuf/queries.hpp"
+#include "module/shared_model/cryptography/crypto_defaults.hpp"
+
+using namespace iroha::torii;
+
+class QueryResponseCacheTest : public ::testing::Test {
+ public:
+  iroha::protocol::Query makeQuery(
+      uint64_t counter,
+      shared_model::crypto::Keypair const &keypair = kKeypair) {
+    return shared_model::proto::QueryBuilder()
+        .creatorAccountId("user@domain")
+        .createdTime(iroha::time::now())
+        .queryCounter(counter)
+        .getAccountAssets("user@domain", 10, std::nullopt)
+        .build()
+        .signAndAddSignature(keypair)
+        .finish()
+        .getTransport();
+  }
+
+  iroha::protocol::QueryResponse makeResponse() {
+    iroha::protocol::QueryResponse response;
+    response.mutable_account_assets_response()->set_total_number(1);
+    return response;
+  }
+
+  static inline auto const kKeypair =
+      shared_model::crypto::DefaultCryptoAlgorithmType::generateKeypair();
+
+  QueryResponseCache cache{10};
+};
+
+/**
+ * @given a cached response to a query
+ * @when the same query is looked up with another counter and created time
+ * @then the cached response is returned
+ */
+TEST_F(QueryResponseCacheTest, HitForRepeatedQuery) {
+  cache.insert(makeQuery(1), makeResponse(), cache.version());
+
+  auto cached = cache.find(makeQuery(2));
+  ASSERT_TRUE(cached);
+  EXPECT_EQ(cached->account_assets_response().total_number(), 1);
+}
+
+/**
+ * @given a cached response to a query
+ * @when the same query signed with another key is looked up
+ * @then nothing is returned
+ */
+TEST_F(QueryResponseCacheTest, MissForAnotherSigner) {
+  cache.insert(makeQuery(1), makeResponse(), cache.version());
+
+  EXPECT_FALSE(cache.find(makeQuery(
+      2, shared_model::crypto::DefaultCryptoAlgorithmType::generateKeypair())));
+}
+
+/**
+ * @given a cached response to a query
+ * @when the cache is invalidated
+ * @then the response is dropped
+ */
+TEST_F(QueryResponseCacheTest, InvalidateDropsResponses) {
+  cache.insert(makeQuery(1), makeResponse(), cache.version());
+  cache.invalidate();
+
+  EXPECT_EQ(cache.size(), 0);
+  EXPECT_FALSE(cache.find(makeQuery(2)));
+}
+
+/**
+ * @given a response computed before the cache invalidation
+ * @when it is inserted after the invalidation
+ * @then it is not stored
+ */
+TEST_F(QueryResponseCacheTest, StaleResponseIsNotStored) {
+  auto version = cache.version();
+  cache.invalidate();
+  cache.insert(makeQuery(1), makeResponse(), version);
+
+  EXPECT_EQ(cache.size(), 0);
+}
+
+/**
+ * @given error and pending transactions responses
+ * @when they are inserted
+ * @then they are not stored
+ */
+TEST_F(QueryResponseCacheTest, NonCacheableResponsesAreNotStored) {
+  iroha::protocol::QueryResponse error_response;
+  error_response.mutable_error_response()->set_reason(
+      iroha::protocol::ErrorResponse::STATEFUL_INVALID);
+  cache.insert(makeQuery(1), error_response, cache.version());
+
+  auto pending_query = shared_model::proto::QueryBuilder()
+                           .creatorAccountId("user@domain")
+                           .createdTime(iroha::time::now())
+                           .queryCounter(1)
+                           .getPendingTransactions()
+                           .build()
+                           .signAndAddSignature(kKeypair)
+                           .finish()
+                           .getTransport();
+  cache.insert(pending_query, makeResponse(), cache.version());
+
+  EXPECT_EQ(cache.size(), 0);
+}
@@ -30,17 +30,7 @@ class QueryServiceTest : public ::testing::Test {
   void SetUp() override {
     query_processor = std::make_shared<MockQueryProcessor>();
     // any query
-    query = std::make_shared<shared_model::proto::Query>(
-        shared_model::proto::QueryBuilder()
-            .creatorAccountId("user@domain")
-            .createdTime(iroha::time::now())
-            .queryCounter(1)
-            .getAccount("user@domain")
-            .build()
-            .signAndAddSignature(
-                shared_model::crypto::DefaultCryptoAlgorithmType::
-                    generateKeypair())
-            .finish());
+    query = makeQuery(1);
 
     std::unique_ptr<shared_model::validation::AbstractValidator<
         shared_model::interface::Query>>
@@ -70,13 +60,26 @@ class QueryServiceTest : public ::testing::Test {
             std::move(proto_blocks_query_validator));
   }
 
-  void init() {
+  void init(std::shared_ptr<QueryResponseCache> response_cache = nullptr) {
     query_service =
         std::make_shared<QueryService>(query_processor,
                                        query_factory,
                                        blocks_query_factory,
                                        getTestLogger("QueryService"),
-                                       nullptr);
+                                       nullptr,
+                                       std::move(response_cache));
+  }
+
+  std::shared_ptr<shared_model::proto::Query> makeQuery(uint64_t counter) {
+    return std::make_shared<shared_model::proto::Query>(
+        shared_model::proto::QueryBuilder()
+            .creatorAccountId("user@domain")
+            .createdTime(iroha::time::now())
+            .queryCounter(counter)
+            .getAccount("user@domain")
+            .build()
+            .signAndAddSignature(keypair)
+            .finish());
   }
 
   std::unique_ptr<shared_model::interface::QueryResponse> getResponse() {
@@ -89,6 +92,8 @@ class QueryServiceTest : public ::testing::Test {
   std::shared_ptr<QueryService::QueryFactoryType> query_factory;
   std::shared_ptr<QueryService::BlocksQueryFactoryType> blocks_query_factory;
   std::shared_ptr<MockQueryProcessor> query_processor;
+  shared_model::crypto::Keypair keypair =
+      shared_model::crypto::DefaultCryptoAlgorithmType::generateKeypair();
 };
 
 /**
@@ -139,3 +144,49 @@ TEST_F(QueryServiceTest, InvalidWhenDuplicateHash) {
           shared_model::interface::StatelessFailedErrorResponse>(),
       resp.get()));
 }
+
+/**
+ * @given query service with response cache and a processed query
+ * @when the same query with another counter is sent
+ * @then query processor is not invoked and the cached response is returned
+ * with the hash of the new query
+ */
+TEST_F(QueryServiceTest, CachedResponseSkipsProcessor) {
+  EXPECT_CALL(*query_processor, queryHandle(_)).WillOnce(Invoke([this](auto &) {
+    return this->getResponse();
+  }));
+  init(std::make_shared<QueryResponseCache>(10));
+
+  protocol::QueryResponse response;
+  query_service->Find(query->getTransport(), response);
+
+  auto repeated_query = makeQuery(2);
+  protocol::QueryResponse cached_response;
+  query_service->Find(repeated_query->getTransport(), cached_response);
+  ASSERT_TRUE(cached_response.has_account_response());
+  EXPECT_EQ(cached_response.account_response().SerializeAsString(),
+            response.account_response().SerializeAsString());
+  EXPECT_EQ(cached_response.query_hash(), repeated_query->hash().hex());
+}
+
+/**
+ * @given query service with response cache and a processed query
+ * @when the cache is invalidated, as it is on block commit, and the same query
+ * with another counter is sent
+ * @then query processor is invoked again
+ */
+TEST_F(QueryServiceTest, InvalidatedCacheInvokesProcessor) {
+  EXPECT_CALL(*query_processor, queryHandle(_))
+      .Times(2)
+      .WillRepeatedly(Invoke([this](auto &) { return this->getResponse(); }));
+  auto response_cache = std::make_shared<QueryResponseCache>(10);
+  init(response_cache);
+
+  protocol::QueryResponse response;
+  query_service->Find(query->getTransport(), response);
+
+  response_cache->invalidate();
+
+  query_service->Find(makeQuery(2)->getTransport(), response);
+  ASSERT_TRUE(response.has_account_response());
+}
@@ -0,0 +1,173 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <limits>
+#include <random>
+
+#include <gtest/gtest.h>
+
+#include "backend/protobuf/queries/proto_query.hpp"
+#include "backend/protobuf/transaction.hpp"
+#include "converters/protobuf/json_proto_converter.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_query_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+
+using namespace shared_model::converters::protobuf;
+
+/**
+ * Round trip property tests: randomly generated transactions, queries and
+ * blocks must keep their serialization and hash after binary and JSON
+ * encoding and decoding.
+ */
+class CodecRoundTripTest : public ::testing::Test {
+ public:
+  static constexpr size_t kIterations = 100;
+
+  /// includes characters escaped in JSON and multibyte UTF-8 characters
+  inline static const std::vector<std::string> kAlphabet{
+      "a", "Z", "0", "_", "@", "#", " ", "\"", "\\", "/", "\n", "\t",
+      "\x01", "\xc3\xa9", "\xe2\x82\xac", "\xf0\x9f\x98\x80"};
+
+  std::string randomString(size_t max_length) {
+    std::string result;
+    auto length = std::uniform_int_distribution<size_t>(0, max_length)(gen_);
+    std::uniform_int_distribution<size_t> symbol(0, kAlphabet.size() - 1);
+    for (size_t i = 0; i < length; ++i) {
+      result += kAlphabet[symbol(gen_)];
+    }
+    return result;
+  }
+
+  template <typename T>
+  T randomNumber(T max = std::numeric_limits<T>::max()) {
+    return std::uniform_int_distribution<T>(0, max)(gen_);
+  }
+
+  std::string randomAmount() {
+    return std::to_string(randomNumber<uint64_t>()) + "."
+        + std::to_string(randomNumber<uint32_t>(9999));
+  }
+
+  shared_model::proto::Transaction randomTransaction() {
+    auto builder = TestTransactionBuilder()
+                       .creatorAccountId(randomString(16))
+                       .createdTime(randomNumber<uint64_t>())
+                       .quorum(randomNumber<uint32_t>(128));
+    auto commands_count = randomNumber<size_t>(4) + 1;
+    for (size_t i = 0; i < commands_count; ++i) {
+      switch (randomNumber<int>(2)) {
+        case 0:
+          builder = builder.setAccountDetail(
+              randomString(16), randomString(16), randomString(64));
+          break;
+        case 1:
+          builder = builder.addAssetQuantity(randomString(16), randomAmount());
+          break;
+        default:
+          builder = builder.transferAsset(randomString(16),
+                                          randomString(16),
+                                          randomString(16),
+                                          randomString(64),
+                                          randomAmount());
+      }
+    }
+    return builder.build();
+  }
+
+  shared_model::proto::Query randomQuery() {
+    auto builder = TestQueryBuilder()
+                       .creatorAccountId(randomString(16))
+                       .createdTime(randomNumber<uint64_t>())
+                       .queryCounter(randomNumber<uint64_t>());
+    if (randomNumber<int>(1) == 0) {
+      return builder
+          .getAccountAssets(randomString(16),
+                            randomNumber<uint32_t>(),
+                            randomNumber<int>(1) == 0
+                                ? std::nullopt
+                                : std::make_optional(randomString(16)))
+          .build();
+    }
+    return builder
+        .getAccountDetail(randomNumber<uint32_t>(),
+                          randomString(16),
+                          randomString(16),
+                          randomString(16))
+        .build();
+  }
+
+  shared_model::proto::Block randomBlock() {
+    std::vector<shared_model::proto::Transaction> txs;
+    auto txs_count = randomNumber<size_t>(4);
+    for (size_t i = 0; i < txs_count; ++i) {
+      txs.push_back(randomTransaction());
+    }
+    return TestBlockBuilder()
+        .transactions(txs)
+        .height(randomNumber<uint64_t>())
+        .prevHash(shared_model::crypto::Hash(randomString(32)))
+        .createdTime(randomNumber<uint64_t>())
+        .build();
+  }
+
+  /**
+   * Check that the model survives binary and JSON round trips
+   */
+  template <typename Model>
+  void checkRoundTrips(const Model &model) {
+    const auto serialized = model.getTransport().SerializeAsString();
+
+    typename Model::TransportType parsed;
+    ASSERT_TRUE(parsed.ParseFromString(serialized));
+    Model reparsed(std::move(parsed));
+    EXPECT_EQ(reparsed.getTransport().SerializeAsString(), serialized);
+    EXPECT_EQ(reparsed.hash().hex(), model.hash().hex());
+
+    auto json_result =
+        jsonToProto<typename Model::TransportType>(modelToJson(model));
+    IROHA_ASSERT_RESULT_VALUE(json_result);
+    Model restored(std::move(json_result).assumeValue());
+    EXPECT_EQ(restored.getTransport().SerializeAsString(), serialized);
+    EXPECT_EQ(restored.hash().hex(), model.hash().hex());
+  }
+
+ private:
+  std::mt19937_64 gen_{::testing::UnitTest::GetInstance()->random_seed()};
+};
+
+/**
+ * @given randomly generated transactions
+ * @when they are encoded to binary and JSON and decoded back
+ * @then serialization and hash of the transactions are not changed
+ */
+TEST_F(CodecRoundTripTest, Transactions) {
+  for (size_t i = 0; i < kIterations; ++i) {
+    ASSERT_NO_FATAL_FAILURE(checkRoundTrips(randomTransaction()));
+  }
+}
+
+/**
+ * @given randomly generated queries
+ * @when they are encoded to binary and JSON and decoded back
+ * @then serialization and hash of the queries are not changed
+ */
+TEST_F(CodecRoundTripTest, Queries) {
+  for (size_t i = 0; i < kIterations; ++i) {
+    ASSERT_NO_FATAL_FAILURE(checkRoundTrips(randomQuery()));
+  }
+}
+
+/**
+ * @given randomly generated blocks
+ * @when they are encoded to binary and JSON and decoded back
+ * @then serialization and hash of the blocks are not changed
+ */
+TEST_F(CodecRoundTripTest, Blocks) {
+  for (size_t i = 0; i < kIterations; ++i) {
+    ASSERT_NO_FATAL_FAILURE(checkRoundTrips(randomBlock()));
+  }
+}